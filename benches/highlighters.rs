@@ -169,14 +169,17 @@ fn bench_individual_highlighters(c: &mut Criterion) {
                         "TRACE".into(),
                     ],
                     style: Style::new().fg(Color::Red),
+                    ..KeywordConfig::default()
                 },
                 KeywordConfig {
                     words: vec!["GET".into(), "POST".into(), "PUT".into(), "DELETE".into()],
                     style: Style::new().fg(Color::Green),
+                    ..KeywordConfig::default()
                 },
                 KeywordConfig {
                     words: vec!["null".into(), "false".into(), "true".into()],
                     style: Style::new().fg(Color::Yellow),
+                    ..KeywordConfig::default()
                 },
             ])
             .build()
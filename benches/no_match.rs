@@ -134,6 +134,7 @@ fn bench_no_match(c: &mut Criterion) {
             .with_regex_highlighter(RegexConfig {
                 regex: r"FOOBAR_\d+".into(),
                 style: Style::new().fg(Color::Red),
+                ..RegexConfig::default()
             })
             .build()
             .unwrap();
@@ -152,14 +153,17 @@ fn bench_no_match(c: &mut Criterion) {
                         "TRACE".into(),
                     ],
                     style: Style::new().fg(Color::Red),
+                    ..KeywordConfig::default()
                 },
                 KeywordConfig {
                     words: vec!["GET".into(), "POST".into(), "PUT".into(), "DELETE".into()],
                     style: Style::new().fg(Color::Green),
+                    ..KeywordConfig::default()
                 },
                 KeywordConfig {
                     words: vec!["null".into(), "false".into(), "true".into()],
                     style: Style::new().fg(Color::Yellow),
+                    ..KeywordConfig::default()
                 },
             ])
             .build()
@@ -23,14 +23,17 @@ fn build_4h() -> Highlighter {
                     "TRACE".into(),
                 ],
                 style: Style::new().fg(Color::Red),
+                ..KeywordConfig::default()
             },
             KeywordConfig {
                 words: vec!["GET".into(), "POST".into(), "PUT".into(), "DELETE".into()],
                 style: Style::new().fg(Color::Green),
+                ..KeywordConfig::default()
             },
             KeywordConfig {
                 words: vec!["null".into(), "false".into(), "true".into()],
                 style: Style::new().fg(Color::Yellow),
+                ..KeywordConfig::default()
             },
         ])
         .with_quote_highlighter(QuoteConfig::default())
@@ -25,7 +25,7 @@ _tspin() {
 
     case "${cmd}" in
         tspin)
-            opts="-f -p -e -h -V --follow --print --theme --exec --highlight --enable --disable --extras --pager --completions --generate-default-theme --help --version"
+            opts="-f -p -e -h -V --follow --print --theme --config --theme-preset --background --color --exec --listen --journal --unit --highlight --enable --disable --extras --pager --completions --align-prefixes --decode-epochs --tz --tz-format --deltas --generate-default-theme --help --version"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -35,6 +35,22 @@ _tspin() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --config)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --theme-preset)
+                    COMPREPLY=($(compgen -W "minimal focus" -- "${cur}"))
+                    return 0
+                    ;;
+                --background)
+                    COMPREPLY=($(compgen -W "auto dark light" -- "${cur}"))
+                    return 0
+                    ;;
+                --color)
+                    COMPREPLY=($(compgen -W "auto always never" -- "${cur}"))
+                    return 0
+                    ;;
                 --exec)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -43,6 +59,14 @@ _tspin() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --listen)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --unit)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --highlight)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -56,7 +80,7 @@ _tspin() {
                     return 0
                     ;;
                 --extras)
-                    COMPREPLY=($(compgen -W "ipv6 jvm-stack-trace" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "ipv6 jvm-stack-trace http-status syslog containers windows-paths source-locations mac hashes sizes epochs" -- "${cur}"))
                     return 0
                     ;;
                 --pager)
@@ -67,6 +91,14 @@ _tspin() {
                     COMPREPLY=($(compgen -W "bash elvish fish powershell zsh" -- "${cur}"))
                     return 0
                     ;;
+                --tz)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --tz-format)
+                    COMPREPLY=($(compgen -W "iso8601 date-time time" -- "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
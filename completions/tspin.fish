@@ -1,7 +1,18 @@
 # === DO NOT EDIT — generated by util/generate_shell_completions.sh ===
 #
 complete -c tspin -l theme -d 'Provide a custom path to a theme file' -r -F
+complete -c tspin -l config -d 'Provide a custom path to a config file of default flags' -r -F
+complete -c tspin -l theme-preset -d 'Start from a bundled theme; theme.toml applies on top' -r -f -a "minimal\t'Structural highlights are faint; dates, keywords and quotes keep their colors'
+focus\t'Error lines are bold, and lines without errors or warnings faint'"
+complete -c tspin -l background -d 'The terminal background the colors are for (default: auto)' -r -f -a "auto\t'Ask the terminal, falling back to dark'
+dark\t''
+light\t''"
+complete -c tspin -l color -d 'When to style the output (default: auto)' -r -f -a "auto\t'Style output to a terminal or pager, honoring NO_COLOR and CLICOLOR_FORCE'
+always\t''
+never\t'Print the text as is; timestamps and prefixes are still rewritten'"
 complete -c tspin -s e -l exec -d 'Run command and view the output in a pager' -r
+complete -c tspin -l listen -d 'Listen for lines on a UDP or TCP socket (e.g. `--listen udp://0.0.0.0:5514`)' -r
+complete -c tspin -l unit -d 'Only show journal entries for the given unit (repeatable)' -r
 complete -c tspin -l highlight -d 'Highlights in the form color:word1,word2' -r
 complete -c tspin -l enable -d 'Enable specific highlighters' -r -f -a "numbers\t''
 urls\t''
@@ -32,15 +43,32 @@ processes\t''
 json\t''
 keywords\t''"
 complete -c tspin -l extras -d 'Enable extra highlighters (e.g., --extras ipv6)' -r -f -a "ipv6\t''
-jvm-stack-trace\t''"
+jvm-stack-trace\t''
+http-status\t''
+syslog\t''
+containers\t''
+windows-paths\t''
+source-locations\t''
+mac\t''
+hashes\t''
+sizes\t''
+epochs\t''"
 complete -c tspin -l pager -d 'Override the default pager command used by tspin. (e.g. `--pager="ov -f [FILE]"`)' -r
 complete -c tspin -l completions -d 'Print shell completions to stdout' -r -f -a "bash\t''
 elvish\t''
 fish\t''
 powershell\t''
 zsh\t''"
+complete -c tspin -l tz -d 'Rewrite timestamps that carry a zone into UTC or a fixed offset (e.g. `--tz +02:00`)' -r
+complete -c tspin -l tz-format -d 'How `--tz` writes timestamps (default: iso8601)' -r -f -a "iso8601\t'2024-10-18T12:00:00.123+02:00'
+date-time\t'2024-10-18 12:00:00.123'
+time\t'12:00:00.123'"
 complete -c tspin -s f -l follow -d 'Follow the contents of a file'
 complete -c tspin -s p -l print -d 'Print the output to stdout'
+complete -c tspin -l journal -d 'Read the systemd journal, or `journalctl -o json`/`-o export` output from a file or stdin'
+complete -c tspin -l align-prefixes -d 'Pad container prefixes (`[pod/name/container]`, `name | `) so messages line up'
+complete -c tspin -l decode-epochs -d 'Append the decoded UTC time after Unix epoch timestamps (implies `--extras epochs`)'
+complete -c tspin -l deltas -d 'Prefix each line with the time since the previous timestamped line (e.g. `+0.153s`)'
 complete -c tspin -l generate-default-theme -d 'Print the default theme as a theme.toml to stdout'
 complete -c tspin -s h -l help -d 'Print help'
 complete -c tspin -s V -l version -d 'Print version'
@@ -0,0 +1,154 @@
+use crate::cli::Background;
+use std::env;
+
+const COLORFGBG_ENV: &str = "COLORFGBG";
+
+/// Whether to use the light styles. `auto` trusts `COLORFGBG` if the terminal
+/// sets it, then asks the terminal itself, and assumes dark if neither answers.
+pub fn is_light(background: Background) -> bool {
+    match background {
+        Background::Dark => false,
+        Background::Light => true,
+        Background::Auto => env::var(COLORFGBG_ENV)
+            .ok()
+            .and_then(|value| light_from_colorfgbg(&value))
+            .or_else(query::light_from_terminal)
+            .unwrap_or(false),
+    }
+}
+
+/// `COLORFGBG` is `fg;bg` (rxvt adds a middle field) in ANSI color numbers.
+/// White and the bright colors, gray (8) aside, are light backgrounds.
+fn light_from_colorfgbg(value: &str) -> Option<bool> {
+    let background: u8 = value.rsplit(';').next()?.parse().ok()?;
+    Some(matches!(background, 7 | 9..=15))
+}
+
+/// The reply to an OSC 11 query, `ESC ] 11 ; rgb:RRRR/GGGG/BBBB`, with one to
+/// four hex digits per channel. Light means a luma above one half.
+fn light_from_osc11(reply: &str) -> Option<bool> {
+    let rgb = &reply[reply.find("rgb:")? + 4..];
+    let end = rgb
+        .find(|c: char| !c.is_ascii_hexdigit() && c != '/')
+        .unwrap_or(rgb.len());
+
+    let mut channels = rgb[..end].split('/').map(|channel| {
+        let max = 16_u32.checked_pow(u32::try_from(channel.len()).ok()?)? - 1;
+        let value = u32::from_str_radix(channel, 16).ok()?;
+        Some(f64::from(value) / f64::from(max))
+    });
+    let (red, green, blue) = (channels.next()??, channels.next()??, channels.next()??);
+
+    Some(0.299 * red + 0.587 * green + 0.114 * blue > 0.5)
+}
+
+#[cfg(unix)]
+mod query {
+    use super::light_from_osc11;
+    use std::fs::{File, OpenOptions};
+    use std::io::{IsTerminal, Read, Write, stdout};
+    use std::process::{Command, Stdio};
+
+    const TTY: &str = "/dev/tty";
+
+    /// Asks the terminal for its background color. The device attributes
+    /// query after it is answered by virtually every terminal, so a terminal
+    /// that ignores OSC 11 ends the read early instead of running into the
+    /// timeout.
+    pub fn light_from_terminal() -> Option<bool> {
+        // With redirected output there is no terminal whose colors matter.
+        if !stdout().is_terminal() {
+            return None;
+        }
+
+        let mut tty = OpenOptions::new().read(true).write(true).open(TTY).ok()?;
+        let saved = stty(&["-g"])?;
+        // Unbuffered, unechoed reads that give up after 0.2s without input.
+        stty(&["-icanon", "-echo", "min", "0", "time", "2"])?;
+
+        let reply = exchange(&mut tty);
+        stty(&[saved.trim()]);
+
+        light_from_osc11(&reply?)
+    }
+
+    fn exchange(tty: &mut File) -> Option<String> {
+        tty.write_all(b"\x1b]11;?\x1b\\\x1b[c").ok()?;
+
+        let mut reply = Vec::new();
+        let mut buffer = [0; 64];
+        while reply.len() < 512 {
+            let read = tty.read(&mut buffer).ok()?;
+            if read == 0 {
+                break;
+            }
+            reply.extend_from_slice(&buffer[..read]);
+            if answered_device_attributes(&reply) {
+                break;
+            }
+        }
+
+        Some(String::from_utf8_lossy(&reply).into_owned())
+    }
+
+    /// The device attributes reply, `ESC [ ? ... c`, comes last.
+    fn answered_device_attributes(reply: &[u8]) -> bool {
+        reply
+            .windows(3)
+            .position(|window| window == b"\x1b[?")
+            .is_some_and(|start| reply[start..].contains(&b'c'))
+    }
+
+    /// Runs `stty` on the terminal, which it reads from its stdin.
+    fn stty(args: &[&str]) -> Option<String> {
+        let output = Command::new("stty")
+            .args(args)
+            .stdin(File::open(TTY).ok()?)
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+#[cfg(not(unix))]
+mod query {
+    pub const fn light_from_terminal() -> Option<bool> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colorfgbg_names_the_background_last() {
+        assert_eq!(light_from_colorfgbg("0;15"), Some(true));
+        assert_eq!(light_from_colorfgbg("0;default;7"), Some(true));
+        assert_eq!(light_from_colorfgbg("15;0"), Some(false));
+        assert_eq!(light_from_colorfgbg("7;8"), Some(false));
+        assert_eq!(light_from_colorfgbg("15;default"), None);
+        assert_eq!(light_from_colorfgbg(""), None);
+    }
+
+    #[test]
+    fn osc11_replies_resolve_by_luma() {
+        assert_eq!(light_from_osc11("\x1b]11;rgb:ffff/ffff/ffff\x1b\\"), Some(true));
+        assert_eq!(light_from_osc11("\x1b]11;rgb:fdf6/f6f6/e3e3\x07"), Some(true));
+        assert_eq!(light_from_osc11("\x1b]11;rgb:1e/1e/2e\x1b\\\x1b[?62;22c"), Some(false));
+        assert_eq!(light_from_osc11("\x1b]11;rgb:0/0/0\x07"), Some(false));
+    }
+
+    #[test]
+    fn malformed_osc11_replies_are_ignored() {
+        assert_eq!(light_from_osc11(""), None);
+        assert_eq!(light_from_osc11("\x1b[?62;22c"), None);
+        assert_eq!(light_from_osc11("\x1b]11;rgb:ffff/ffff\x07"), None);
+        assert_eq!(light_from_osc11("\x1b]11;rgb:/ffff/ffff\x07"), None);
+    }
+}
@@ -1,27 +1,48 @@
 use tailspin::config::KeywordConfig;
 use tailspin::style::{Color, Style};
 
-pub fn builtin_keywords() -> Vec<KeywordConfig> {
+/// With `light`, the severity styles that are hard to read on a light
+/// background (yellow, white, faint) give way to magenta, black and gray.
+pub fn builtin_keywords(light: bool) -> Vec<KeywordConfig> {
+    let (warn, info, trace) = if light {
+        (
+            Style::new().fg(Color::Magenta),
+            Style::new().fg(Color::Black),
+            Style::new().fg(Color::BrightBlack),
+        )
+    } else {
+        (
+            Style::new().fg(Color::Yellow),
+            Style::new().fg(Color::White),
+            Style::new().faint(),
+        )
+    };
+
     let severity_levels = vec![
         KeywordConfig {
             words: vec!["ERROR".to_string()],
             style: Style::new().fg(Color::Red),
+            ..KeywordConfig::default()
         },
         KeywordConfig {
             words: vec!["WARN".to_string(), "WARNING".to_string()],
-            style: Style::new().fg(Color::Yellow),
+            style: warn,
+            ..KeywordConfig::default()
         },
         KeywordConfig {
             words: vec!["INFO".to_string()],
-            style: Style::new().fg(Color::White),
+            style: info,
+            ..KeywordConfig::default()
         },
         KeywordConfig {
             words: vec!["SUCCESS".to_string(), "DEBUG".to_string()],
             style: Style::new().fg(Color::Green),
+            ..KeywordConfig::default()
         },
         KeywordConfig {
             words: vec!["TRACE".to_string()],
-            style: Style::new().faint(),
+            style: trace,
+            ..KeywordConfig::default()
         },
     ];
 
@@ -29,22 +50,27 @@ pub fn builtin_keywords() -> Vec<KeywordConfig> {
         KeywordConfig {
             words: vec!["GET".to_string()],
             style: Style::new().fg(Color::Black).on(Color::Green),
+            ..KeywordConfig::default()
         },
         KeywordConfig {
             words: vec!["POST".to_string()],
             style: Style::new().fg(Color::Black).on(Color::Yellow),
+            ..KeywordConfig::default()
         },
         KeywordConfig {
             words: vec!["PUT".to_string(), "PATCH".to_string()],
             style: Style::new().fg(Color::Black).on(Color::Magenta),
+            ..KeywordConfig::default()
         },
         KeywordConfig {
             words: vec!["DELETE".to_string()],
             style: Style::new().fg(Color::Black).on(Color::Red),
+            ..KeywordConfig::default()
         },
         KeywordConfig {
             words: vec!["HEAD".to_string(), "CONNECT".to_string(), "OPTIONS".to_string()],
             style: Style::new().fg(Color::Black).on(Color::Blue),
+            ..KeywordConfig::default()
         },
     ];
 
@@ -61,10 +87,12 @@ pub fn builtin_keywords() -> Vec<KeywordConfig> {
                 "FALSE".to_string(),
             ],
             style: Style::new().fg(Color::Red).italic(),
+            ..KeywordConfig::default()
         },
         KeywordConfig {
             words: vec!["true".to_string(), "TRUE".to_string()],
             style: Style::new().fg(Color::Green).italic(),
+            ..KeywordConfig::default()
         },
     ];
 
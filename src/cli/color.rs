@@ -0,0 +1,61 @@
+use crate::cli::ColorChoice;
+use std::env;
+use std::io::{IsTerminal, stdout};
+
+const NO_COLOR_ENV: &str = "NO_COLOR";
+const CLICOLOR_FORCE_ENV: &str = "CLICOLOR_FORCE";
+
+/// Whether to style the output. The pager always gets styles, since it renders
+/// them; output printed to stdout gets them when stdout is a terminal.
+pub fn is_enabled(choice: ColorChoice, to_stdout: bool) -> bool {
+    resolve(
+        choice,
+        env::var(NO_COLOR_ENV).ok().as_deref(),
+        env::var(CLICOLOR_FORCE_ENV).ok().as_deref(),
+        !to_stdout || stdout().is_terminal(),
+    )
+}
+
+/// `--color` wins over the environment, and under `auto` a non-empty
+/// `NO_COLOR` wins over a `CLICOLOR_FORCE` other than `0`.
+fn resolve(choice: ColorChoice, no_color: Option<&str>, force: Option<&str>, terminal: bool) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            if no_color.is_some_and(|value| !value.is_empty()) {
+                false
+            } else if force.is_some_and(|value| !value.is_empty() && value != "0") {
+                true
+            } else {
+                terminal
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_follows_the_terminal() {
+        assert!(resolve(ColorChoice::Auto, None, None, true));
+        assert!(!resolve(ColorChoice::Auto, None, None, false));
+    }
+
+    #[test]
+    fn no_color_disables_and_clicolor_force_enables() {
+        assert!(!resolve(ColorChoice::Auto, Some("1"), None, true));
+        assert!(resolve(ColorChoice::Auto, Some(""), None, true));
+        assert!(resolve(ColorChoice::Auto, None, Some("1"), false));
+        assert!(!resolve(ColorChoice::Auto, None, Some("0"), false));
+        assert!(!resolve(ColorChoice::Auto, Some("1"), Some("1"), true));
+    }
+
+    #[test]
+    fn the_flag_overrides_the_environment() {
+        assert!(resolve(ColorChoice::Always, Some("1"), None, false));
+        assert!(!resolve(ColorChoice::Never, None, Some("1"), true));
+    }
+}
@@ -13,8 +13,12 @@ use tailspin::style::Style;
 /// (an error under CI's `-D warnings`).
 pub fn default_theme_toml() -> String {
     let Theme {
+        extends: _,
+        include: _,
+        compose,
         keywords: _,
         regexes: _,
+        lines: _,
         numbers,
         uuids,
         quotes,
@@ -30,6 +34,15 @@ pub fn default_theme_toml() -> String {
         key_value_pairs,
         json,
         jvm_stack_traces,
+        http_status,
+        syslog,
+        containers,
+        windows_paths,
+        source_locations,
+        mac,
+        hashes,
+        sizes,
+        deltas,
     } = Theme::default();
 
     let mut out = String::from(
@@ -40,12 +53,19 @@ pub fn default_theme_toml() -> String {
 # ~/.config/tailspin/theme.toml and leaving it unchanged does nothing.
 # Edit the styles you want to override and delete the rest.
 #
-# Keyword ([[keywords]]) and regex ([[regexes]]) highlights are additive
-# lists and default to empty; the built-in keywords are compiled into tspin.
-# See the README for their syntax.
+# Keyword ([[keywords]]), regex ([[regexes]]) and line ([[lines]]) highlights
+# are additive lists and default to empty; the built-in keywords are compiled
+# into tspin. See the README for their syntax.
 ",
     );
 
+    writeln!(
+        out,
+        "\n# Layer overlapping highlights: attributes and colors a style leaves\n\
+         # unset show the highlights beneath it.\ncompose = {compose}"
+    )
+    .unwrap();
+
     push_config(&mut out, "numbers", &numbers);
     push_config(&mut out, "uuids", &uuids);
     push_quotes(&mut out, quotes);
@@ -61,6 +81,15 @@ pub fn default_theme_toml() -> String {
     push_config(&mut out, "key_value_pairs", &key_value_pairs);
     push_config(&mut out, "json", &json);
     push_config(&mut out, "jvm_stack_traces", &jvm_stack_traces);
+    push_config(&mut out, "http_status", &http_status);
+    push_config(&mut out, "syslog", &syslog);
+    push_containers(&mut out, &containers);
+    push_config(&mut out, "windows_paths", &windows_paths);
+    push_config(&mut out, "source_locations", &source_locations);
+    push_config(&mut out, "mac", &mac);
+    push_hashes(&mut out, hashes);
+    push_config(&mut out, "sizes", &sizes);
+    push_deltas(&mut out, deltas);
 
     out
 }
@@ -88,12 +117,38 @@ fn push_table(out: &mut String, name: &str, styles: &[(&str, Style)]) {
     }
 }
 
-/// `[quotes]` is the one table with a non-style field.
+/// `[quotes]` has a non-style field, the quote character.
 fn push_quotes(out: &mut String, config: QuoteConfig) {
     writeln!(out, "\n[quotes]\nquote_token = '{}'", config.quote_token as char).unwrap();
     writeln!(out, "style = {}", inline_style(config.style)).unwrap();
 }
 
+/// `[hashes]` has a non-style field, the minimum digest length.
+fn push_hashes(out: &mut String, config: HashConfig) {
+    writeln!(out, "\n[hashes]\nmin_length = {}", config.min_length).unwrap();
+    writeln!(out, "prefix = {}", inline_style(config.prefix)).unwrap();
+    writeln!(out, "digest = {}", inline_style(config.digest)).unwrap();
+}
+
+/// `[deltas]` has a non-style field, the warning threshold.
+fn push_deltas(out: &mut String, config: DeltaConfig) {
+    writeln!(out, "\n[deltas]").unwrap();
+    if let Some(warn) = config.warn {
+        writeln!(out, "warn = \"{}\"", String::from(warn)).unwrap();
+    }
+    writeln!(out, "style = {}", inline_style(config.style)).unwrap();
+    writeln!(out, "warn_style = {}", inline_style(config.warn_style)).unwrap();
+}
+
+/// `[containers]` holds a list of styles, one array element per line.
+fn push_containers(out: &mut String, config: &ContainerConfig) {
+    writeln!(out, "\n[containers]\nnames = [").unwrap();
+    for style in &config.names {
+        writeln!(out, "    {},", inline_style(*style)).unwrap();
+    }
+    writeln!(out, "]\nseparator = {}", inline_style(config.separator)).unwrap();
+}
+
 /// A style as a TOML inline table, e.g. `{ fg = "magenta", italic = true }`.
 fn inline_style(style: Style) -> String {
     let table = toml::Value::try_from(style).expect("styles serialize to tables");
@@ -119,5 +174,9 @@ mod tests {
         assert_eq!(theme.quotes.quote_token, b'"');
         assert_eq!(theme.ipv4.separator, IpV4Config::default().separator);
         assert_eq!(theme.ipv6.letter, IpV6Config::default().letter);
+        assert_eq!(theme.containers.names, ContainerConfig::default().names);
+        assert_eq!(theme.hashes.min_length, HashConfig::default().min_length);
+        assert_eq!(theme.deltas.warn, DeltaConfig::default().warn);
+        assert!(!theme.compose);
     }
 }
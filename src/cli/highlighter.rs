@@ -1,21 +1,39 @@
 use crate::cli::keywords::collect_keywords;
 use crate::cli::resolution::BaseSet;
 use crate::cli::{Base, Extra};
+use crate::io::reader::delta;
 use crate::theme::Theme;
 use std::collections::HashSet;
+use tailspin::config::EpochConfig;
 use tailspin::style::Color;
 use tailspin::{Highlighter, HighlighterBuilder};
 
+/// What the highlighter writes: no styles, or the styles for a dark or light
+/// terminal background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Styling {
+    Plain,
+    Dark,
+    Light,
+}
+
 // Registration order below is highlight precedence: earlier finders win overlaps.
 pub(crate) fn build_highlighter(
     base: &BaseSet,
     extras: &HashSet<Extra>,
     theme: Theme,
     color_word: &[(Color, Vec<String>)],
+    decode_epochs: bool,
+    deltas: bool,
+    styling: Styling,
 ) -> Result<Highlighter, tailspin::Error> {
     let Theme {
+        extends: _,
+        include: _,
+        compose,
         keywords,
         regexes,
+        lines,
         numbers,
         uuids,
         quotes,
@@ -31,30 +49,83 @@ pub(crate) fn build_highlighter(
         key_value_pairs,
         json,
         jvm_stack_traces,
+        http_status,
+        syslog,
+        containers,
+        windows_paths,
+        source_locations,
+        mac,
+        hashes,
+        sizes,
+        deltas: delta_styles,
     } = theme;
 
-    let keywords = collect_keywords(color_word, base.contains(Base::Keywords), keywords);
+    let keywords = collect_keywords(
+        color_word,
+        base.contains(Base::Keywords),
+        styling == Styling::Light,
+        keywords,
+    );
 
     let mut b = Highlighter::builder();
 
+    // The delta column is tspin's own text, not the log's: the other
+    // highlighters run on the rest of the line.
+    if deltas {
+        b = b.with_delta_highlighter(delta_styles, delta::COLUMN);
+    }
+
     if base.contains(Base::Json) {
         b = b.with_json_highlighter(json);
     }
 
     b = regexes.into_iter().fold(b, HighlighterBuilder::with_regex_highlighter);
+    b = lines.into_iter().fold(b, HighlighterBuilder::with_line_highlighter);
 
     if base.contains(Base::Dates) {
         b = b.with_date_time_highlighter(dates);
     }
-    if base.contains(Base::Ipv4) {
-        b = b.with_ip_v4_highlighter(ipv4);
+    // Epochs take the date styles; decoding them implies the extra.
+    if extras.contains(&Extra::Epochs) || decode_epochs {
+        b = b.with_epoch_highlighter(EpochConfig {
+            date_time: dates,
+            annotate: decode_epochs,
+        });
     }
+    // IPv6 goes first so it keeps the IPv4 tail of `::ffff:192.168.1.1`.
     if extras.contains(&Extra::Ipv6) {
         b = b.with_ip_v6_highlighter(ipv6);
     }
+    if base.contains(Base::Ipv4) {
+        b = b.with_ip_v4_highlighter(ipv4);
+    }
     if extras.contains(&Extra::JvmStackTrace) {
         b = b.with_jvm_stack_trace_highlighter(jvm_stack_traces);
     }
+    if extras.contains(&Extra::HttpStatus) {
+        b = b.with_http_status_highlighter(http_status);
+    }
+    if extras.contains(&Extra::Syslog) {
+        b = b.with_syslog_highlighter(syslog);
+    }
+    if extras.contains(&Extra::Containers) {
+        b = b.with_container_highlighter(containers);
+    }
+    if extras.contains(&Extra::WindowsPaths) {
+        b = b.with_windows_path_highlighter(windows_paths);
+    }
+    if extras.contains(&Extra::SourceLocations) {
+        b = b.with_source_location_highlighter(source_locations);
+    }
+    if extras.contains(&Extra::Mac) {
+        b = b.with_mac_highlighter(mac);
+    }
+    if extras.contains(&Extra::Hashes) {
+        b = b.with_hash_highlighter(hashes);
+    }
+    if extras.contains(&Extra::Sizes) {
+        b = b.with_size_highlighter(sizes);
+    }
     if base.contains(Base::Urls) {
         b = b.with_url_highlighter(urls);
     }
@@ -89,5 +160,13 @@ pub(crate) fn build_highlighter(
         b = b.with_quote_highlighter(quotes);
     }
 
+    if compose {
+        b = b.with_style_composition();
+    }
+    // Plain output still runs every highlighter, for the text they add.
+    if styling == Styling::Plain {
+        b = b.with_plain_output();
+    }
+
     b.build()
 }
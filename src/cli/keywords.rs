@@ -8,10 +8,11 @@ use tailspin::style::{Color, Style};
 pub fn collect_keywords(
     color_word: &[(Color, Vec<String>)],
     include_builtins: bool,
+    light: bool,
     theme_keywords: Vec<KeywordConfig>,
 ) -> Vec<KeywordConfig> {
     let builtin = if include_builtins {
-        builtin_keywords()
+        builtin_keywords(light)
     } else {
         Vec::new()
     };
@@ -36,6 +37,7 @@ fn get_keywords_from_cli(color_word: &[(Color, Vec<String>)]) -> Vec<KeywordConf
             words.iter().map(move |word| KeywordConfig {
                 style: Style::new().fg(*color),
                 words: vec![word.clone()],
+                ..KeywordConfig::default()
             })
         })
         .collect()
@@ -49,6 +51,7 @@ mod tests {
         KeywordConfig {
             words: words.iter().map(ToString::to_string).collect(),
             style,
+            ..KeywordConfig::default()
         }
     }
 
@@ -1,20 +1,25 @@
+mod background;
 mod builtins;
+mod color;
 mod completions;
 mod default_theme;
 mod highlighter;
 pub(crate) mod keywords;
 #[cfg(test)]
 mod parity;
+mod profile;
 pub(crate) mod resolution;
+mod settings;
 mod styles;
 
 use crate::cli::completions::generate_shell_completions_and_exit_or_continue;
-use crate::cli::highlighter::build_highlighter;
+use crate::cli::highlighter::{Styling, build_highlighter};
 use crate::cli::resolution::{BaseSet, resolve_extras};
 use crate::cli::styles::{
     get_styles, help_with_env, help_with_possible_values, help_with_possible_values_and_env, help_with_value_list,
 };
-use crate::io::routing::{self, IoArgs, Source, Target};
+use crate::io::routing::{self, IoArgs, ListenAddr, Source, Target};
+use crate::theme::preset::Preset;
 use crate::theme::reader;
 use anyhow::Result;
 use clap::builder::StyledStr;
@@ -25,10 +30,12 @@ use std::io::{IsTerminal, stdin};
 use std::path::PathBuf;
 use tailspin::Highlighter;
 use tailspin::style::Color;
+use tailspin::time::{TimestampFormat, UtcOffset};
 
 const THEME_ENV: &str = "TAILSPIN_THEME";
 const EXTRAS_ENV: &str = "TAILSPIN_EXTRAS";
 const PAGER_ENV: &str = "TAILSPIN_PAGER";
+const CONFIG_ENV: &str = "TAILSPIN_CONFIG";
 
 #[derive(Parser)]
 #[command(
@@ -51,21 +58,59 @@ pub struct Arguments {
     pub file_path: Option<PathBuf>,
 
     /// Follow the contents of a file
-    #[clap(short = 'f', long = "follow")]
+    #[clap(short = 'f', long = "follow", overrides_with = "no_follow")]
     pub follow: bool,
 
+    /// Turn off `follow`, when config.toml turns it on
+    #[clap(long = "no-follow", overrides_with = "follow", hide = true)]
+    no_follow: bool,
+
     /// Print the output to stdout
-    #[clap(short = 'p', long = "print")]
+    #[clap(short = 'p', long = "print", overrides_with = "no_to_stdout")]
     pub to_stdout: bool,
 
+    /// Turn off `print`, when config.toml turns it on
+    #[clap(long = "no-print", overrides_with = "to_stdout", hide = true)]
+    no_to_stdout: bool,
+
     #[clap(long = "theme", value_name = "PATH", env = THEME_ENV, hide_env = true,
            help = help_with_env("Provide a custom path to a theme file", THEME_ENV))]
     pub theme: Option<PathBuf>,
 
+    #[clap(long = "config", value_name = "PATH", env = CONFIG_ENV, hide_env = true,
+           help = help_with_env("Provide a custom path to a config file of default flags", CONFIG_ENV))]
+    pub config: Option<PathBuf>,
+
+    #[clap(long = "theme-preset", value_enum, value_name = "NAME", hide_possible_values = true,
+           help = help_with_possible_values::<Preset>("Start from a bundled theme; theme.toml applies on top"))]
+    pub theme_preset: Option<Preset>,
+
+    #[clap(long = "background", value_enum, value_name = "SHADE", default_value_t = Background::Auto,
+           hide_possible_values = true, hide_default_value = true,
+           help = help_with_possible_values::<Background>("The terminal background the colors are for (default: auto)"))]
+    pub background: Background,
+
+    #[clap(long = "color", value_enum, value_name = "WHEN", default_value_t = ColorChoice::Auto,
+           hide_possible_values = true, hide_default_value = true,
+           help = help_with_possible_values::<ColorChoice>("When to style the output (default: auto)"))]
+    pub color: ColorChoice,
+
     /// Run command and view the output in a pager
     #[clap(short = 'e', long = "exec", value_name = "COMMAND")]
     pub exec: Option<String>,
 
+    /// Listen for lines on a UDP or TCP socket (e.g. `--listen udp://0.0.0.0:5514`)
+    #[clap(long = "listen", value_name = "ADDR", value_parser = parse_listen)]
+    pub listen: Option<ListenAddr>,
+
+    /// Read the systemd journal, or `journalctl -o json`/`-o export` output from a file or stdin
+    #[clap(long = "journal")]
+    pub journal: bool,
+
+    /// Only show journal entries for the given unit (repeatable)
+    #[clap(long = "unit", value_name = "UNIT", requires = "journal")]
+    pub units: Vec<String>,
+
     #[arg(long = "highlight", value_name = "COLOR:WORDS", value_parser = parse_highlight, help = highlight_help())]
     pub color_word: Vec<(Color, Vec<String>)>,
 
@@ -90,6 +135,39 @@ pub struct Arguments {
            help = help_with_possible_values::<clap_complete::Shell>("Print shell completions to stdout"))]
     pub completions: Option<clap_complete::Shell>,
 
+    /// Pad container prefixes (`[pod/name/container]`, `name | `) so messages line up
+    #[clap(long = "align-prefixes", overrides_with = "no_align_prefixes")]
+    pub align_prefixes: bool,
+
+    /// Turn off `align-prefixes`, when config.toml turns it on
+    #[clap(long = "no-align-prefixes", overrides_with = "align_prefixes", hide = true)]
+    no_align_prefixes: bool,
+
+    /// Append the decoded UTC time after Unix epoch timestamps (implies `--extras epochs`)
+    #[clap(long = "decode-epochs", overrides_with = "no_decode_epochs")]
+    pub decode_epochs: bool,
+
+    /// Turn off `decode-epochs`, when config.toml turns it on
+    #[clap(long = "no-decode-epochs", overrides_with = "decode_epochs", hide = true)]
+    no_decode_epochs: bool,
+
+    /// Rewrite timestamps that carry a zone into UTC or a fixed offset (e.g. `--tz +02:00`)
+    #[clap(long = "tz", value_name = "ZONE", value_parser = parse_time_zone)]
+    pub time_zone: Option<UtcOffset>,
+
+    #[clap(long = "tz-format", value_enum, value_name = "FORMAT", default_value_t = TimeFormat::Iso8601,
+           requires = "time_zone", hide_possible_values = true, hide_default_value = true,
+           help = help_with_possible_values::<TimeFormat>("How `--tz` writes timestamps (default: iso8601)"))]
+    pub time_format: TimeFormat,
+
+    /// Prefix each line with the time since the previous timestamped line (e.g. `+0.153s`)
+    #[clap(long = "deltas", overrides_with = "no_deltas")]
+    pub deltas: bool,
+
+    /// Turn off `deltas`, when config.toml turns it on
+    #[clap(long = "no-deltas", overrides_with = "deltas", hide = true)]
+    no_deltas: bool,
+
     /// Print the default theme as a theme.toml to stdout
     #[clap(long = "generate-default-theme")]
     pub generate_default_theme: bool,
@@ -134,6 +212,14 @@ fn parse_highlight(s: &str) -> Result<(Color, Vec<String>), Box<dyn Error + Send
     Ok((color, words))
 }
 
+fn parse_time_zone(s: &str) -> Result<UtcOffset, Box<dyn Error + Send + Sync>> {
+    Ok(s.parse()?)
+}
+
+fn parse_listen(s: &str) -> Result<ListenAddr, Box<dyn Error + Send + Sync>> {
+    Ok(ListenAddr::parse(s)?)
+}
+
 /// The same `snake_case` color names `theme.toml` accepts, minus `default`.
 fn parse_color(s: &str) -> Result<Color, String> {
     match s.to_lowercase().as_str() {
@@ -163,6 +249,55 @@ fn parse_color(s: &str) -> Result<Color, String> {
 pub enum Extra {
     Ipv6,
     JvmStackTrace,
+    HttpStatus,
+    Syslog,
+    Containers,
+    WindowsPaths,
+    SourceLocations,
+    Mac,
+    Hashes,
+    Sizes,
+    Epochs,
+}
+
+/// The `--color` choices.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Style output to a terminal or pager, honoring `NO_COLOR` and `CLICOLOR_FORCE`
+    Auto,
+    Always,
+    /// Print the text as is; timestamps and prefixes are still rewritten
+    Never,
+}
+
+/// The `--background` choices.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Background {
+    /// Ask the terminal, falling back to dark
+    Auto,
+    Dark,
+    Light,
+}
+
+/// The `--tz-format` choices, one per [`TimestampFormat`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum TimeFormat {
+    /// 2024-10-18T12:00:00.123+02:00
+    Iso8601,
+    /// 2024-10-18 12:00:00.123
+    DateTime,
+    /// 12:00:00.123
+    Time,
+}
+
+impl From<TimeFormat> for TimestampFormat {
+    fn from(format: TimeFormat) -> Self {
+        match format {
+            TimeFormat::Iso8601 => TimestampFormat::Iso8601,
+            TimeFormat::DateTime => TimestampFormat::DateTime,
+            TimeFormat::Time => TimestampFormat::Time,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Hash)]
@@ -187,10 +322,17 @@ pub struct FullConfig {
     pub source: Source,
     pub target: Target,
     pub highlighter: Highlighter,
+    pub align_prefixes: bool,
+    pub time_zone: Option<(UtcOffset, TimestampFormat)>,
+    pub deltas: bool,
 }
 
 pub fn get_config() -> Result<FullConfig> {
-    let cli = Arguments::parse();
+    let settings::Settings {
+        arguments: cli,
+        keywords,
+        regexes,
+    } = settings::parse_arguments()?;
 
     generate_shell_completions_and_exit_or_continue(&cli);
 
@@ -200,7 +342,8 @@ pub fn get_config() -> Result<FullConfig> {
     }
 
     let std_in_has_data = !stdin().is_terminal();
-    if cli.file_path.is_none() && cli.exec.is_none() && !std_in_has_data {
+    let has_input = cli.file_path.is_some() || cli.exec.is_some() || cli.listen.is_some() || cli.journal;
+    if !has_input && !std_in_has_data {
         let style = Style::new().fg(nu_ansi_term::Color::Cyan);
         eprintln!("Missing filename ({} for help)", style.paint("tspin --help"));
 
@@ -210,6 +353,9 @@ pub fn get_config() -> Result<FullConfig> {
     let (source, target) = routing::resolve(IoArgs {
         file_path: cli.file_path.clone(),
         exec: cli.exec.clone(),
+        listen: cli.listen.clone(),
+        journal: cli.journal,
+        units: cli.units.clone(),
         to_stdout: cli.to_stdout,
         follow: cli.follow,
         pager: cli.pager.clone(),
@@ -219,13 +365,37 @@ pub fn get_config() -> Result<FullConfig> {
     let base = BaseSet::resolve(&cli.enabled, &cli.disabled)?;
     let extras = resolve_extras(&cli.extras);
 
-    let theme = reader::parse_theme(cli.theme.as_ref())?;
-    let highlighter = build_highlighter(&base, &extras, theme, &cli.color_word)?;
+    // Without color the theme is still read and the highlighters still run:
+    // a broken theme is reported, and text they add, like decoded epochs, is
+    // written unstyled.
+    let styling = if !color::is_enabled(cli.color, matches!(target, Target::Stdout)) {
+        Styling::Plain
+    } else if background::is_light(cli.background) {
+        Styling::Light
+    } else {
+        Styling::Dark
+    };
+    let mut theme = reader::parse_theme(cli.theme.as_ref(), cli.theme_preset, styling == Styling::Light)?;
+    // Profile keywords come after the theme's, so they win on the same word.
+    theme.keywords.extend(keywords);
+    theme.regexes.extend(regexes);
+    let highlighter = build_highlighter(
+        &base,
+        &extras,
+        theme,
+        &cli.color_word,
+        cli.decode_epochs,
+        cli.deltas,
+        styling,
+    )?;
 
     Ok(FullConfig {
         source,
         target,
         highlighter,
+        align_prefixes: cli.align_prefixes,
+        time_zone: cli.time_zone.map(|offset| (offset, cli.time_format.into())),
+        deltas: cli.deltas,
     })
 }
 
@@ -6,7 +6,7 @@
 //! list it.
 
 use crate::cli::builtins::builtin_keywords;
-use crate::cli::highlighter::build_highlighter;
+use crate::cli::highlighter::{Styling, build_highlighter};
 use crate::cli::resolution::{BaseSet, resolve_extras};
 use crate::cli::{Base, Extra};
 use crate::theme::Theme;
@@ -37,13 +37,31 @@ fn extra_exemplar(extra: Extra) -> &'static str {
     match extra {
         Extra::Ipv6 => "peer 2001:db8::ff00:42:8329",
         Extra::JvmStackTrace => "        at com.example.EmailService.send(EmailService.kt:171)",
+        Extra::HttpStatus => "upstream responded 503",
+        Extra::Syslog => "Oct 11 22:14:15 mymachine su[1234]: 'su root' failed",
+        Extra::Containers => "[pod/web-7d4b9c-xk2p/nginx] ready",
+        Extra::WindowsPaths => "C:\\Users\\build\\src\\main.cs",
+        Extra::SourceLocations => "src/io/routing.rs:42:7",
+        Extra::Mac => "3c:22:fb:12:ab:cd",
+        Extra::Hashes => "commit a1b2c3d",
+        Extra::Sizes => "heap 512MiB",
+        Extra::Epochs => r#"{"ts":1729245600.123}"#,
     }
 }
 
 /// The theme is empty and builtin keywords ride the `keywords` group, so
 /// only the groups under test can produce highlights.
 fn build(base: &BaseSet, extras: &[Extra]) -> Highlighter {
-    build_highlighter(base, &resolve_extras(extras), Theme::default(), &[]).unwrap()
+    build_highlighter(
+        base,
+        &resolve_extras(extras),
+        Theme::default(),
+        &[],
+        false,
+        false,
+        Styling::Dark,
+    )
+    .unwrap()
 }
 
 fn only(base: Base) -> BaseSet {
@@ -147,7 +165,7 @@ fn library_default_matches_the_cli_default_configuration() {
 fn every_builtin_keyword_highlights_by_default() {
     let h = build(&BaseSet::resolve(&[], &[]).unwrap(), &[]);
 
-    for group in builtin_keywords() {
+    for group in builtin_keywords(false) {
         for word in &group.words {
             assert_ne!(
                 h.apply(word).as_ref(),
@@ -0,0 +1,154 @@
+use regex::Regex;
+use std::path::{Path, absolute};
+use tailspin::config::{KeywordConfig, RegexConfig};
+use toml::Table;
+
+/// A `[[profiles]]` entry of `config.toml`: default flags, keywords and regexes
+/// for the sources it selects, by a glob on the file path or a regex on the
+/// `--exec` command.
+#[derive(Debug)]
+pub struct Profile {
+    path: Option<Regex>,
+    command: Option<Regex>,
+    pub flags: Table,
+    pub keywords: Vec<KeywordConfig>,
+    pub regexes: Vec<RegexConfig>,
+}
+
+impl Profile {
+    /// Splits the selectors, keywords and regexes off `table`; the rest are
+    /// flags, validated by the caller like the top-level settings.
+    pub fn new(mut table: Table) -> Result<Self, String> {
+        let mut selector = |key: &str| match table.remove(key) {
+            None => Ok(None),
+            Some(toml::Value::String(pattern)) => Ok(Some(pattern)),
+            Some(_) => Err(format!("`{key}` must be a string")),
+        };
+        let path = selector("path")?;
+        let command = selector("command")?;
+        if path.is_none() && command.is_none() {
+            return Err("a profile needs a `path` glob or a `command` regex".to_string());
+        }
+
+        let mut list = |key: &str| table.remove(key).unwrap_or_else(|| toml::Value::Array(Vec::new()));
+        let keywords = list("keywords")
+            .try_into()
+            .map_err(|err| format!("`keywords`: {err}"))?;
+        let regexes = list("regexes").try_into().map_err(|err| format!("`regexes`: {err}"))?;
+
+        let compile = |pattern: &str| Regex::new(pattern).map_err(|err| err.to_string());
+        Ok(Self {
+            path: path
+                .as_deref()
+                .map(glob_to_regex)
+                .map(|regex| compile(&regex))
+                .transpose()?,
+            command: command.as_deref().map(compile).transpose()?,
+            flags: table,
+            keywords,
+            regexes,
+        })
+    }
+
+    /// A profile selects a source if its `path` glob matches the file or its
+    /// `command` regex matches somewhere in the `--exec` command.
+    pub fn selects(&self, file_path: Option<&Path>, exec: Option<&str>) -> bool {
+        let path_matches = self
+            .path
+            .as_ref()
+            .zip(file_path)
+            .is_some_and(|(glob, file_path)| matches_path(glob, file_path));
+        let command_matches = self
+            .command
+            .as_ref()
+            .zip(exec)
+            .is_some_and(|(regex, exec)| regex.is_match(exec));
+
+        path_matches || command_matches
+    }
+}
+
+/// A glob without a `/` is matched against the file name, like `*.jsonl`; one
+/// with a `/` against the absolute path, like `*/catalina.out`.
+fn matches_path(glob: &Regex, file_path: &Path) -> bool {
+    if glob.as_str().contains('/') {
+        absolute(file_path).is_ok_and(|path| glob.is_match(&path.to_string_lossy()))
+    } else {
+        file_path
+            .file_name()
+            .is_some_and(|name| glob.is_match(&name.to_string_lossy()))
+    }
+}
+
+/// `*` matches any run of characters, `/` included, and `?` any one.
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+    for c in glob.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            c => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(selectors: &str) -> Profile {
+        Profile::new(toml::from_str(selectors).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn globs_without_a_slash_match_the_file_name() {
+        let jsonl = profile("path = '*.jsonl'");
+        assert!(jsonl.selects(Some(Path::new("logs/app.jsonl")), None));
+        assert!(jsonl.selects(Some(Path::new("app.jsonl")), None));
+        assert!(!jsonl.selects(Some(Path::new("app.jsonl.1")), None));
+        assert!(!jsonl.selects(None, Some("cat app.jsonl")));
+    }
+
+    #[test]
+    fn globs_with_a_slash_match_the_absolute_path() {
+        let catalina = profile("path = '*/catalina.out'");
+        assert!(catalina.selects(Some(Path::new("/opt/tomcat/logs/catalina.out")), None));
+        assert!(catalina.selects(Some(Path::new("catalina.out")), None));
+        assert!(!catalina.selects(Some(Path::new("/opt/tomcat/logs/catalina.out.1")), None));
+    }
+
+    #[test]
+    fn command_regexes_match_anywhere_in_the_exec_command() {
+        let kubectl = profile(r"command = '^kubectl\s+logs'");
+        assert!(kubectl.selects(None, Some("kubectl logs -f pod")));
+        assert!(!kubectl.selects(None, Some("docker logs pod")));
+        assert!(!kubectl.selects(Some(Path::new("kubectl")), None));
+    }
+
+    #[test]
+    fn keywords_and_regexes_are_split_from_the_flags() {
+        let profile = profile(
+            r"
+path = '*.log'
+extras = ['ipv6']
+keywords = [{ words = ['OOM'], style = { fg = 'red' } }]
+regexes = [{ regex = 'req-\d+', style = { fg = 'cyan' } }]
+",
+        );
+
+        assert_eq!(profile.keywords[0].words, ["OOM"]);
+        assert_eq!(profile.regexes[0].regex, r"req-\d+");
+        assert_eq!(profile.flags.keys().collect::<Vec<_>>(), ["extras"]);
+    }
+
+    #[test]
+    fn invalid_profiles_are_rejected() {
+        let new = |source: &str| Profile::new(toml::from_str(source).unwrap());
+        assert!(new("extras = ['ipv6']").is_err());
+        assert!(new("path = 1").is_err());
+        assert!(new("command = '('").is_err());
+        assert!(new("path = '*.log'\nkeywords = [{ bogus = 1 }]").is_err());
+    }
+}
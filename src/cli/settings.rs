@@ -0,0 +1,414 @@
+use crate::cli::Arguments;
+use crate::cli::profile::Profile;
+use crate::theme::reader::get_config_dir;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Command, CommandFactory, FromArgMatches};
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::Path;
+use tailspin::config::{KeywordConfig, RegexConfig};
+use thiserror::Error;
+use toml::{Table, Value};
+
+/// Flags that pick what to read or do once rather than how: a default for
+/// them would make every run the same command, so they stay on the command line.
+const COMMAND_LINE_ONLY: [&str; 9] = [
+    "help",
+    "version",
+    "config",
+    "exec",
+    "listen",
+    "journal",
+    "unit",
+    "completions",
+    "generate-default-theme",
+];
+
+/// The arguments, and the keywords and regexes the selected profiles add to
+/// the theme.
+pub struct Settings {
+    pub arguments: Arguments,
+    pub keywords: Vec<KeywordConfig>,
+    pub regexes: Vec<RegexConfig>,
+}
+
+impl From<Arguments> for Settings {
+    fn from(arguments: Arguments) -> Self {
+        Settings {
+            arguments,
+            keywords: Vec::new(),
+            regexes: Vec::new(),
+        }
+    }
+}
+
+/// Flags that choose the same thing between them: one on the command line
+/// replaces the defaults of the others too, so `--enable` never meets a
+/// `disable` default.
+const LINKED: [&[&str]; 1] = [&["enable", "disable"]];
+
+/// Parses the command line over the defaults in `config.toml`, whose keys are
+/// long flag names. A default applies unless its flag is on the command line
+/// or set through its environment variable; the `[[profiles]]` that select the
+/// input override the top-level defaults.
+pub fn parse_arguments() -> Result<Settings, SettingsError> {
+    let args: Vec<OsString> = env::args_os().collect();
+    let command = Arguments::command();
+
+    // The command line alone, to find the config file and the flags it sets.
+    let given = command.clone().get_matches_from(&args);
+    let cli = Arguments::from_arg_matches(&given).unwrap_or_else(|err| err.exit());
+
+    let path = match &cli.config {
+        Some(path) => path.clone(),
+        None => match get_config_dir() {
+            Ok(dir) => dir.join("tailspin").join("config.toml"),
+            Err(_) => return Ok(cli.into()),
+        },
+    };
+    let mut settings = match read_settings(&path) {
+        Err(SettingsError::Read(_, err)) if cli.config.is_none() && err.kind() == io::ErrorKind::NotFound => {
+            return Ok(cli.into());
+        }
+        other => other?,
+    };
+
+    let display_path = path.display().to_string();
+    let profiles = profiles_from(&mut settings, &command, &given, &display_path)?;
+    let selected = profiles
+        .into_iter()
+        .filter(|profile| profile.selects(cli.file_path.as_deref(), cli.exec.as_deref()));
+    let (keywords, regexes) = apply_profiles(&mut settings, selected);
+    let defaults = defaults_from(settings, &command, &given, &display_path)?;
+
+    // The defaults go first so that positional arguments and `--` keep working.
+    let mut argv = args;
+    argv.splice(1..1, defaults);
+
+    let matches = command.try_get_matches_from(argv);
+    let arguments = matches
+        .and_then(|matches| Arguments::from_arg_matches(&matches))
+        .map_err(|err| {
+            let message = err.to_string();
+            let first_line = message.lines().next().unwrap_or_default();
+            SettingsError::Rejected(display_path, first_line.trim_start_matches("error: ").to_string())
+        })?;
+
+    Ok(Settings {
+        arguments,
+        keywords,
+        regexes,
+    })
+}
+
+fn read_settings(path: &Path) -> Result<Table, SettingsError> {
+    let display_path = || path.display().to_string();
+
+    let content = fs::read_to_string(path).map_err(|err| SettingsError::Read(display_path(), err))?;
+
+    toml::from_str::<Table>(&content).map_err(|err| SettingsError::Parsing(display_path(), err))
+}
+
+/// Takes the `[[profiles]]` out of the settings. Their flags are checked even
+/// if they select nothing, so a typo shows up before the input it is meant for.
+fn profiles_from(
+    settings: &mut Table,
+    command: &Command,
+    given: &ArgMatches,
+    path: &str,
+) -> Result<Vec<Profile>, SettingsError> {
+    let not_tables = || SettingsError::Value(path.to_string(), "profiles".to_string(), "a list of tables");
+    let profiles = match settings.remove("profiles") {
+        None => return Ok(Vec::new()),
+        Some(Value::Array(profiles)) => profiles,
+        Some(_) => return Err(not_tables()),
+    };
+
+    let mut compiled = Vec::with_capacity(profiles.len());
+    for (index, profile) in profiles.into_iter().enumerate() {
+        let Value::Table(table) = profile else {
+            return Err(not_tables());
+        };
+        let profile =
+            Profile::new(table).map_err(|message| SettingsError::Profile(path.to_string(), index + 1, message))?;
+        defaults_from(profile.flags.clone(), command, given, path)?;
+        compiled.push(profile);
+    }
+
+    Ok(compiled)
+}
+
+/// Lays the flags of the selected profiles over the settings, later profiles
+/// winning, and collects their keywords and regexes in order. A profile that
+/// sets one of the [`LINKED`] flags replaces all of them.
+fn apply_profiles(
+    settings: &mut Table,
+    selected: impl IntoIterator<Item = Profile>,
+) -> (Vec<KeywordConfig>, Vec<RegexConfig>) {
+    let mut keywords = Vec::new();
+    let mut regexes = Vec::new();
+
+    for profile in selected {
+        for keys in LINKED {
+            if keys.iter().any(|key| profile.flags.contains_key(*key)) {
+                for key in keys {
+                    settings.remove(*key);
+                }
+            }
+        }
+        settings.extend(profile.flags);
+        keywords.extend(profile.keywords);
+        regexes.extend(profile.regexes);
+    }
+
+    (keywords, regexes)
+}
+
+/// Turns each setting into the flags that would set it on the command line:
+/// `follow = true` into `--follow`, `extras = ["ipv6", "mac"]` into
+/// `--extras=ipv6 --extras=mac`.
+fn defaults_from(
+    settings: Table,
+    command: &Command,
+    given: &ArgMatches,
+    path: &str,
+) -> Result<Vec<OsString>, SettingsError> {
+    let invalid = |key: &str, expected| SettingsError::Value(path.to_string(), key.to_string(), expected);
+    let is_given = |long: &str| {
+        command
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(long))
+            .and_then(|arg| given.value_source(arg.get_id().as_str()))
+            .is_some_and(|source| matches!(source, ValueSource::CommandLine | ValueSource::EnvVariable))
+    };
+    let mut defaults = Vec::new();
+
+    for (key, value) in settings {
+        let arg = command
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(key.as_str()))
+            .ok_or_else(|| SettingsError::Unknown(path.to_string(), key.clone()))?;
+        if COMMAND_LINE_ONLY.contains(&key.as_str()) {
+            return Err(SettingsError::CommandLineOnly(path.to_string(), key));
+        }
+
+        let linked = LINKED.iter().find(|keys| keys.contains(&key.as_str()));
+        if linked.map_or_else(|| is_given(&key), |keys| keys.iter().any(|&long| is_given(long))) {
+            continue;
+        }
+
+        let takes_values = arg.get_action().takes_values();
+        match value {
+            Value::Boolean(set) if !takes_values => {
+                if set {
+                    defaults.push(format!("--{key}").into());
+                }
+            }
+            _ if !takes_values => return Err(invalid(&key, "true or false")),
+            Value::Array(items) => {
+                for item in items {
+                    let flag = flag_with_value(&key, item).ok_or_else(|| invalid(&key, SCALARS))?;
+                    defaults.push(flag);
+                }
+            }
+            value => defaults.push(flag_with_value(&key, value).ok_or_else(|| invalid(&key, SCALARS))?),
+        }
+    }
+
+    Ok(defaults)
+}
+
+const SCALARS: &str = "a string, number or list of them";
+
+fn flag_with_value(key: &str, value: Value) -> Option<OsString> {
+    let value = match value {
+        Value::String(value) => value,
+        Value::Integer(value) => value.to_string(),
+        Value::Float(value) => value.to_string(),
+        Value::Boolean(value) => value.to_string(),
+        _ => return None,
+    };
+
+    Some(format!("--{key}={value}").into())
+}
+
+#[derive(Debug, Error)]
+pub enum SettingsError {
+    #[error("could not read {0}")]
+    Read(String, #[source] io::Error),
+
+    #[error("could not parse {0}: {1}")]
+    Parsing(String, toml::de::Error),
+
+    #[error("unknown setting `{1}` in {0}")]
+    Unknown(String, String),
+
+    #[error("`{1}` in {0} can only be given on the command line")]
+    CommandLineOnly(String, String),
+
+    #[error("`{1}` in {0} must be {2}")]
+    Value(String, String, &'static str),
+
+    #[error("invalid setting in {0}: {1}")]
+    Rejected(String, String),
+
+    #[error("invalid profile #{1} in {0}: {2}")]
+    Profile(String, usize, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    fn defaults(settings: &str, command_line: &[&str]) -> Result<Vec<String>, SettingsError> {
+        let command = Arguments::command();
+        let given = command
+            .clone()
+            .try_get_matches_from(std::iter::once("tspin").chain(command_line.iter().copied()))
+            .unwrap();
+        let settings = toml::from_str::<Table>(settings).unwrap();
+
+        defaults_from(settings, &command, &given, "config.toml")
+            .map(|flags| flags.into_iter().map(|flag| flag.into_string().unwrap()).collect())
+    }
+
+    #[test]
+    fn settings_become_flags() {
+        let flags = defaults(
+            "follow = true\nprint = false\nextras = [\"ipv6\", \"mac\"]\ntz = \"+02:00\"\n",
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(flags, ["--follow", "--extras=ipv6", "--extras=mac", "--tz=+02:00"]);
+    }
+
+    #[test]
+    fn the_command_line_overrides_settings() {
+        let flags = defaults("extras = [\"ipv6\"]\npager = \"ov -f [FILE]\"\n", &["--extras", "mac"]).unwrap();
+
+        assert_eq!(flags, ["--pager=ov -f [FILE]"]);
+    }
+
+    #[test]
+    fn enable_and_disable_are_overridden_together() {
+        let settings = "disable = [\"numbers\"]\nfollow = true\n";
+
+        assert_eq!(defaults(settings, &["--enable", "dates"]).unwrap(), ["--follow"]);
+        assert_eq!(
+            defaults("enable = [\"dates\"]\n", &["--disable", "numbers"]).unwrap(),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn switches_from_settings_can_be_turned_off() {
+        let arguments =
+            Arguments::try_parse_from(["tspin", "--follow", "--print", "--no-follow", "--no-print"]).unwrap();
+        assert!(!arguments.follow);
+        assert!(!arguments.to_stdout);
+
+        let arguments = Arguments::try_parse_from(["tspin", "--no-deltas", "--deltas"]).unwrap();
+        assert!(arguments.deltas);
+    }
+
+    #[test]
+    fn invalid_settings_are_rejected() {
+        assert!(matches!(defaults("bogus = 1", &[]), Err(SettingsError::Unknown(_, key)) if key == "bogus"));
+        assert!(matches!(
+            defaults("exec = \"ls\"", &[]),
+            Err(SettingsError::CommandLineOnly(_, key)) if key == "exec"
+        ));
+        assert!(matches!(
+            defaults("follow = \"yes\"", &[]),
+            Err(SettingsError::Value(_, key, "true or false")) if key == "follow"
+        ));
+        assert!(matches!(
+            defaults("theme = { path = \"x\" }", &[]),
+            Err(SettingsError::Value(..))
+        ));
+    }
+
+    #[test]
+    fn selected_profiles_override_the_top_level_settings() {
+        let mut settings = toml::from_str::<Table>(
+            r#"
+follow = true
+extras = ["ipv6"]
+
+[[profiles]]
+path = "*.jsonl"
+extras = ["jvm-stack-trace"]
+keywords = [{ words = ["OOM"] }]
+
+[[profiles]]
+command = "^kubectl"
+extras = ["mac"]
+
+[[profiles]]
+path = "*.jsonl"
+disable = ["emails"]
+regexes = [{ regex = 'req-\d+', style = { fg = "cyan" } }]
+"#,
+        )
+        .unwrap();
+        let command = Arguments::command();
+        let given = command.clone().try_get_matches_from(["tspin"]).unwrap();
+
+        let profiles = profiles_from(&mut settings, &command, &given, "config.toml").unwrap();
+        let selected = profiles
+            .into_iter()
+            .filter(|profile| profile.selects(Some(Path::new("app.jsonl")), None));
+        let (keywords, regexes) = apply_profiles(&mut settings, selected);
+        let flags = defaults_from(settings, &command, &given, "config.toml").unwrap();
+
+        assert_eq!(flags, ["--follow", "--extras=jvm-stack-trace", "--disable=emails"]);
+        assert_eq!(keywords[0].words, ["OOM"]);
+        assert_eq!(regexes[0].regex, r"req-\d+");
+    }
+
+    #[test]
+    fn a_profile_choosing_groups_replaces_both_enable_and_disable() {
+        let mut settings = toml::from_str::<Table>(
+            r#"
+disable = ["numbers"]
+
+[[profiles]]
+path = "*.log"
+enable = ["dates", "keywords"]
+"#,
+        )
+        .unwrap();
+        let command = Arguments::command();
+        let given = command.clone().try_get_matches_from(["tspin"]).unwrap();
+
+        let profiles = profiles_from(&mut settings, &command, &given, "config.toml").unwrap();
+        apply_profiles(&mut settings, profiles);
+        let flags = defaults_from(settings, &command, &given, "config.toml").unwrap();
+
+        assert_eq!(flags, ["--enable=dates", "--enable=keywords"]);
+    }
+
+    #[test]
+    fn profiles_are_checked_even_when_unselected() {
+        let check = |source: &str| {
+            let mut settings = toml::from_str::<Table>(source).unwrap();
+            let command = Arguments::command();
+            let given = command.clone().try_get_matches_from(["tspin"]).unwrap();
+            profiles_from(&mut settings, &command, &given, "config.toml").map(|_| ())
+        };
+
+        assert!(matches!(
+            check("[[profiles]]\npath = '*.nothing'\nbogus = 1"),
+            Err(SettingsError::Unknown(_, key)) if key == "bogus"
+        ));
+        assert!(matches!(
+            check("[[profiles]]\nextras = ['mac']"),
+            Err(SettingsError::Profile(_, 1, _))
+        ));
+        assert!(matches!(check("profiles = 1"), Err(SettingsError::Value(..))));
+    }
+}
@@ -1,5 +1,7 @@
 use crate::style::{Color, Style};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
 
 /// Configuration for highlighting numeric values.
 #[derive(Debug, Clone, Copy, Deserialize, Serialize)]
@@ -7,6 +9,16 @@ use serde::{Deserialize, Serialize};
 pub struct NumberConfig {
     /// Style applied to numbers.
     pub style: Style,
+    /// Style for a number at or above `warn`.
+    pub warn_style: Style,
+    /// Style for a number at or above `error`.
+    pub error_style: Style,
+    /// Numbers from this one up use `warn_style`. Off by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warn: Option<f64>,
+    /// Numbers from this one up use `error_style`. Off by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<f64>,
 }
 
 /// Configuration for highlighting UUIDs.
@@ -31,6 +43,16 @@ pub struct DurationConfig {
     pub separator: Style,
     /// Style applied to the time unit (`ns`, `us`, `ms`, `s`, `m`, `h`).
     pub unit: Style,
+    /// Style for a whole duration at or above `warn`.
+    pub warn_style: Style,
+    /// Style for a whole duration at or above `error`.
+    pub error_style: Style,
+    /// Durations from this one up use `warn_style` (e.g. `"500ms"`). Off by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warn: Option<TimeSpan>,
+    /// Durations from this one up use `error_style` (e.g. `"5s"`). Off by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<TimeSpan>,
 }
 
 /// Configuration for highlighting email addresses.
@@ -77,8 +99,12 @@ pub struct DateTimeConfig {
 pub struct IpV4Config {
     /// Style for numeric segments.
     pub number: Style,
-    /// Style for dot separators (`.`).
+    /// Style for dot separators (`.`) and the `/` and `:` before a suffix.
     pub separator: Style,
+    /// Style for a CIDR prefix length (the `24` in `10.0.0.0/24`).
+    pub cidr: Style,
+    /// Style for a port (the `8080` in `10.0.0.1:8080`).
+    pub port: Style,
 }
 
 /// Configuration for highlighting IPv6 addresses.
@@ -89,8 +115,15 @@ pub struct IpV6Config {
     pub number: Style,
     /// Style for alphabetic characters.
     pub letter: Style,
-    /// Style for colon separators (`:`).
+    /// Style for colon separators (`:`), brackets, and the `%`, `/` and `:`
+    /// before a zone ID, prefix length or port.
     pub separator: Style,
+    /// Style for a zone ID (the `eth0` in `fe80::1%eth0`).
+    pub zone: Style,
+    /// Style for a CIDR prefix length (the `64` in `2001:db8::/64`).
+    pub cidr: Style,
+    /// Style for a port (the `443` in `[2001:db8::1]:443`).
+    pub port: Style,
 }
 
 /// Configuration for highlighting URLs.
@@ -219,30 +252,328 @@ pub struct JvmStackTraceConfig {
     pub line_number: Style,
 }
 
+/// Configuration for highlighting HTTP status codes by class.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpStatusConfig {
+    /// Style for `1xx` informational codes.
+    pub informational: Style,
+    /// Style for `2xx` success codes.
+    pub success: Style,
+    /// Style for `3xx` redirection codes.
+    pub redirection: Style,
+    /// Style for `4xx` client error codes.
+    pub client_error: Style,
+    /// Style for `5xx` server error codes.
+    pub server_error: Style,
+}
+
+/// Configuration for highlighting syslog headers (RFC 3164 and RFC 5424).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SyslogConfig {
+    /// Style for the `<PRI>` value, which encodes facility and severity.
+    pub priority: Style,
+    /// Style for the RFC 5424 protocol version.
+    pub version: Style,
+    /// Style for the hostname.
+    pub hostname: Style,
+    /// Style for the application name (the `TAG` in RFC 3164).
+    pub app: Style,
+    /// Style for the process ID.
+    pub pid: Style,
+    /// Style for the RFC 5424 message ID.
+    pub msgid: Style,
+    /// Style for structured data element IDs (e.g. `exampleSDID@32473`).
+    pub sd_id: Style,
+    /// Style for structured data parameter names.
+    pub sd_param_key: Style,
+    /// Style for quoted structured data parameter values.
+    pub sd_param_value: Style,
+    /// Style for the `<>`, `[]`, `=` and `:` punctuation.
+    pub symbol: Style,
+}
+
+/// Configuration for highlighting container prefixes in multiplexed logs
+/// (`kubectl logs --prefix`, `docker compose logs`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ContainerConfig {
+    /// Styles for container names, handed out in the order containers first
+    /// appear. Each container keeps its style for the whole stream.
+    pub names: Vec<Style>,
+    /// Style for the `[pod/`, `/`, `]` and `|` around names.
+    pub separator: Style,
+}
+
+/// Configuration for highlighting Windows drive and UNC paths.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WindowsPathConfig {
+    /// Style for the drive letter and its colon (e.g. `C:`).
+    pub drive: Style,
+    /// Style for path segments, including the server and share of UNC paths.
+    pub segment: Style,
+    /// Style for path separators (`\` and `/`).
+    pub separator: Style,
+}
+
+/// Configuration for highlighting source locations (`path/to/file.rs:line:col`).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SourceLocationConfig {
+    /// Style for path segments, including the file name.
+    pub segment: Style,
+    /// Style for path separators (`/`) and the colons before the line and column.
+    pub separator: Style,
+    /// Style for the line number.
+    pub line: Style,
+    /// Style for the column number.
+    pub column: Style,
+}
+
+/// Configuration for highlighting MAC addresses.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MacConfig {
+    /// Style for numeric characters.
+    pub number: Style,
+    /// Style for alphabetic characters.
+    pub letter: Style,
+    /// Style for separators (`:`, `-` or `.`).
+    pub separator: Style,
+}
+
+/// Configuration for highlighting hex digests (git SHAs, checksums, `sha256:` digests).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HashConfig {
+    /// Style for an algorithm prefix (`sha256:`, `sha1:`, `md5:`, ...).
+    pub prefix: Style,
+    /// Style for the hex digest itself.
+    pub digest: Style,
+    /// Shortest unprefixed hex token treated as a digest.
+    pub min_length: usize,
+}
+
+/// Configuration for highlighting byte sizes (`512MiB`, `1.2 GB`) and percentages (`87%`).
+///
+/// Thresholds are off by default. Once set, a size or percentage at or above
+/// one is styled as a whole with `warn_style` or `error_style`.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SizeConfig {
+    /// Style applied to the numeric value.
+    pub value: Style,
+    /// Style applied to the decimal separator (`.`).
+    pub separator: Style,
+    /// Style applied to the unit (`B`, `KiB`, `MB`, `bytes`, `%`, ...).
+    pub unit: Style,
+    /// Style for a value at or above its `warn` threshold.
+    pub warn_style: Style,
+    /// Style for a value at or above its `error` threshold.
+    pub error_style: Style,
+    /// Sizes from this one up use `warn_style` (e.g. `"1GiB"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_warn: Option<ByteSize>,
+    /// Sizes from this one up use `error_style` (e.g. `"4GiB"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_error: Option<ByteSize>,
+    /// Percentages from this one up use `warn_style` (e.g. `80`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_warn: Option<f64>,
+    /// Percentages from this one up use `error_style` (e.g. `95`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_error: Option<f64>,
+}
+
+/// A number of bytes, written in `theme.toml` as a size like `"512MiB"` or `"1.2 GB"`.
+/// `kB`/`KB`, `MB`, ... are powers of 1000 and `KiB`, `MiB`, ... powers of 1024.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    /// `value` of `unit`, or `None` for an unknown unit.
+    #[must_use]
+    pub fn from_value(value: f64, unit: &str) -> Option<ByteSize> {
+        let multiplier: u64 = match unit {
+            "B" | "bytes" => 1,
+            "kB" | "KB" => 1_000,
+            "MB" => 1_000_000,
+            "GB" => 1_000_000_000,
+            "TB" => 1_000_000_000_000,
+            "PB" => 1_000_000_000_000_000,
+            "KiB" => 1 << 10,
+            "MiB" => 1 << 20,
+            "GiB" => 1 << 30,
+            "TiB" => 1 << 40,
+            "PiB" => 1 << 50,
+            _ => return None,
+        };
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        Some(ByteSize((value * multiplier as f64) as u64))
+    }
+}
+
+impl TryFrom<String> for ByteSize {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let split = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+        let (value, unit) = s.split_at(split);
+
+        value
+            .parse::<f64>()
+            .ok()
+            .and_then(|value| ByteSize::from_value(value, unit.trim_start()))
+            .ok_or_else(|| format!("invalid size `{s}` (expected e.g. \"512MiB\" or \"1.2 GB\")"))
+    }
+}
+
+impl From<ByteSize> for String {
+    fn from(size: ByteSize) -> Self {
+        format!("{}B", size.0)
+    }
+}
+
+/// A duration, written in `theme.toml` like the durations the highlighter
+/// finds: `"500ms"`, `"2.5s"`, `"1h"`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TimeSpan(pub Duration);
+
+impl TimeSpan {
+    /// `value` of `unit` (`ns`, `us`, `ms`, `s`, `m` or `h`), or `None` for an
+    /// unknown unit or a duration too long to represent.
+    #[must_use]
+    pub fn from_value(value: f64, unit: &str) -> Option<TimeSpan> {
+        let seconds = match unit {
+            "ns" => 1e-9,
+            "us" => 1e-6,
+            "ms" => 1e-3,
+            "s" => 1.0,
+            "m" => 60.0,
+            "h" => 3600.0,
+            _ => return None,
+        };
+
+        Duration::try_from_secs_f64(value * seconds).ok().map(TimeSpan)
+    }
+}
+
+impl TryFrom<String> for TimeSpan {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let split = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+        let (value, unit) = s.split_at(split);
+
+        value
+            .parse::<f64>()
+            .ok()
+            .and_then(|value| TimeSpan::from_value(value, unit.trim_start()))
+            .ok_or_else(|| format!("invalid duration `{s}` (expected e.g. \"500ms\" or \"5s\")"))
+    }
+}
+
+impl From<TimeSpan> for String {
+    /// In the coarsest unit that keeps the value whole, e.g. `"500ms"`.
+    fn from(span: TimeSpan) -> Self {
+        let nanos = span.0.as_nanos();
+        let (value, unit) = [(1_000_000_000, "s"), (1_000_000, "ms"), (1_000, "us")]
+            .into_iter()
+            .find(|&(per_unit, _)| nanos.is_multiple_of(per_unit))
+            .map_or((nanos, "ns"), |(per_unit, unit)| (nanos / per_unit, unit));
+
+        format!("{value}{unit}")
+    }
+}
+
+/// Configuration for highlighting the `+0.153s` prefixes that `tspin --deltas`
+/// puts before each line: the time since the previous line's timestamp.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeltaConfig {
+    /// Style for a delta below `warn`.
+    pub style: Style,
+    /// Style for a delta at or above `warn`.
+    pub warn_style: Style,
+    /// Deltas from this one up (either way) use `warn_style`. `"1s"` by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warn: Option<TimeSpan>,
+}
+
+/// Configuration for highlighting Unix epoch timestamps next to time-like keys
+/// (`"ts":1729245600.123`, `created_at=1729245600123`).
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EpochConfig {
+    /// Styles shared with dates: `date` for the epoch, `separator` for the decoded time.
+    pub date_time: DateTimeConfig,
+    /// Append the decoded ISO 8601 UTC time after each epoch.
+    pub annotate: bool,
+}
+
 /// Configuration for highlighting custom keywords.
 #[derive(PartialEq, Eq, Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(default, deny_unknown_fields)]
 pub struct KeywordConfig {
     /// List of keywords to highlight.
     pub words: Vec<String>,
     /// Style to apply to the keywords.
     pub style: Style,
+    /// Match the words in any ASCII case (`Error`, `error`, `ERROR`).
+    pub case_insensitive: bool,
+    /// Only match whole words. Turn off to match inside identifiers, like
+    /// `OutOfMemory` in `java.lang.OutOfMemoryError`.
+    pub whole_word: bool,
+}
+
+/// Configuration for styling whole lines, e.g. tinting every `ERROR` line or
+/// dimming every line without one.
+#[derive(PartialEq, Eq, Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LineConfig {
+    /// Words that select a line, matched as whole words.
+    pub words: Vec<String>,
+    /// Regex that selects a line, alongside `words`.
+    pub regex: Option<String>,
+    /// Select the lines that match neither `words` nor `regex` instead.
+    pub invert: bool,
+    /// Style laid under the whole line; the highlights are drawn over it.
+    pub style: Style,
 }
 
 /// Configuration for highlighting custom regex patterns.
-#[derive(PartialEq, Eq, Debug, Clone, Deserialize)]
+#[derive(PartialEq, Eq, Debug, Clone, Default, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct RegexConfig {
     /// Regex pattern for matching text.
     pub regex: String,
-    /// Style applied to regex matches.
+    /// Style applied to regex matches. With `groups`, it covers the rest of
+    /// the match and may be left unset.
+    #[serde(default)]
     pub style: Style,
+    /// Styles for named capture groups (`(?P<name>...)`), so one regex can
+    /// color several parts of its match.
+    #[serde(default)]
+    pub groups: BTreeMap<String, Style>,
 }
 
 impl Default for NumberConfig {
     fn default() -> Self {
         NumberConfig {
             style: Style::new().fg(Color::Cyan),
+            warn_style: Style::new().fg(Color::Yellow),
+            error_style: Style::new().fg(Color::Red).bold(),
+            warn: None,
+            error: None,
         }
     }
 }
@@ -253,6 +584,10 @@ impl Default for DurationConfig {
             value: Style::new().fg(Color::Blue),
             separator: Style::new().fg(Color::Magenta),
             unit: Style::new().fg(Color::Magenta).italic(),
+            warn_style: Style::new().fg(Color::Yellow),
+            error_style: Style::new().fg(Color::Red).bold(),
+            warn: None,
+            error: None,
         }
     }
 }
@@ -303,6 +638,8 @@ impl Default for IpV4Config {
         IpV4Config {
             number: Style::new().fg(Color::Blue).italic(),
             separator: Style::new().fg(Color::Red),
+            cidr: Style::new().fg(Color::Magenta),
+            port: Style::new().fg(Color::Cyan),
         }
     }
 }
@@ -313,6 +650,9 @@ impl Default for IpV6Config {
             number: Style::new().fg(Color::Blue).italic(),
             letter: Style::new().fg(Color::Magenta).italic(),
             separator: Style::new().fg(Color::Red),
+            zone: Style::new().fg(Color::Green),
+            cidr: Style::new().fg(Color::Magenta),
+            port: Style::new().fg(Color::Cyan),
         }
     }
 }
@@ -395,3 +735,133 @@ impl Default for QuoteConfig {
         }
     }
 }
+
+impl Default for HttpStatusConfig {
+    fn default() -> Self {
+        HttpStatusConfig {
+            informational: Style::new().fg(Color::Blue),
+            success: Style::new().fg(Color::Green),
+            redirection: Style::new().fg(Color::Cyan),
+            client_error: Style::new().fg(Color::Yellow),
+            server_error: Style::new().fg(Color::Red).bold(),
+        }
+    }
+}
+
+impl Default for SyslogConfig {
+    fn default() -> Self {
+        SyslogConfig {
+            priority: Style::new().fg(Color::Magenta),
+            version: Style::new().faint(),
+            hostname: Style::new().fg(Color::Blue),
+            app: Style::new().fg(Color::Yellow),
+            pid: Style::new().fg(Color::Cyan),
+            msgid: Style::new().fg(Color::Magenta).italic(),
+            sd_id: Style::new().fg(Color::Green),
+            sd_param_key: Style::new().faint(),
+            sd_param_value: Style::new().fg(Color::Yellow).italic(),
+            symbol: Style::new().fg(Color::Red),
+        }
+    }
+}
+
+impl Default for ContainerConfig {
+    fn default() -> Self {
+        // Red is left out so a container never reads as an error.
+        let colors = [
+            Color::Cyan,
+            Color::Magenta,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::BrightCyan,
+            Color::BrightMagenta,
+            Color::BrightGreen,
+            Color::BrightYellow,
+            Color::BrightBlue,
+        ];
+
+        ContainerConfig {
+            names: colors.into_iter().map(|color| Style::new().fg(color)).collect(),
+            separator: Style::new().faint(),
+        }
+    }
+}
+
+impl Default for WindowsPathConfig {
+    fn default() -> Self {
+        WindowsPathConfig {
+            drive: Style::new().fg(Color::Magenta),
+            segment: Style::new().fg(Color::Green),
+            separator: Style::new().fg(Color::Yellow),
+        }
+    }
+}
+
+impl Default for SourceLocationConfig {
+    fn default() -> Self {
+        SourceLocationConfig {
+            segment: Style::new().fg(Color::Green),
+            separator: Style::new().fg(Color::Yellow),
+            line: Style::new().fg(Color::Cyan),
+            column: Style::new().fg(Color::Magenta),
+        }
+    }
+}
+
+impl Default for MacConfig {
+    fn default() -> Self {
+        MacConfig {
+            number: Style::new().fg(Color::Blue).italic(),
+            letter: Style::new().fg(Color::Magenta).italic(),
+            separator: Style::new().fg(Color::Red),
+        }
+    }
+}
+
+impl Default for HashConfig {
+    fn default() -> Self {
+        HashConfig {
+            prefix: Style::new().faint(),
+            digest: Style::new().fg(Color::Yellow),
+            min_length: 7,
+        }
+    }
+}
+
+impl Default for SizeConfig {
+    fn default() -> Self {
+        SizeConfig {
+            value: Style::new().fg(Color::Blue),
+            separator: Style::new().fg(Color::Magenta),
+            unit: Style::new().fg(Color::Magenta).italic(),
+            warn_style: Style::new().fg(Color::Yellow),
+            error_style: Style::new().fg(Color::Red).bold(),
+            size_warn: None,
+            size_error: None,
+            percent_warn: None,
+            percent_error: None,
+        }
+    }
+}
+
+impl Default for DeltaConfig {
+    fn default() -> Self {
+        DeltaConfig {
+            style: Style::new().faint(),
+            warn_style: Style::new().fg(Color::Yellow).bold(),
+            warn: TimeSpan::from_value(1.0, "s"),
+        }
+    }
+}
+
+impl Default for KeywordConfig {
+    fn default() -> Self {
+        KeywordConfig {
+            words: Vec::new(),
+            style: Style::new(),
+            case_insensitive: false,
+            whole_word: true,
+        }
+    }
+}
@@ -1,23 +1,36 @@
 use crate::core::config::*;
 use crate::core::span_pipeline::Pipeline;
+use crate::core::span_pipeline::finders::container::ContainerFinder;
+use crate::core::span_pipeline::finders::date_compact::DateCompactFinder;
 use crate::core::span_pipeline::finders::date_dash::DateDashFinder;
+use crate::core::span_pipeline::finders::date_month::DateMonthFinder;
 use crate::core::span_pipeline::finders::date_time::DateTimeFinder;
+use crate::core::span_pipeline::finders::delta::DeltaFinder;
 use crate::core::span_pipeline::finders::duration::DurationFinder;
 use crate::core::span_pipeline::finders::email::EmailFinder;
+use crate::core::span_pipeline::finders::epoch::EpochFinder;
+use crate::core::span_pipeline::finders::hash::HashFinder;
+use crate::core::span_pipeline::finders::http_status::HttpStatusFinder;
 use crate::core::span_pipeline::finders::ip_v4::IpV4Finder;
 use crate::core::span_pipeline::finders::ip_v6::IpV6Finder;
 use crate::core::span_pipeline::finders::json::JsonFinder;
 use crate::core::span_pipeline::finders::jvm_stack::JvmStackFinder;
 use crate::core::span_pipeline::finders::key_value::KeyValueFinder;
 use crate::core::span_pipeline::finders::keyword::KeywordFinder;
+use crate::core::span_pipeline::finders::mac::MacFinder;
 use crate::core::span_pipeline::finders::number::NumberFinder;
 use crate::core::span_pipeline::finders::pointer::PointerFinder;
 use crate::core::span_pipeline::finders::quote::QuoteFinder;
 use crate::core::span_pipeline::finders::regex::RegexFinder;
+use crate::core::span_pipeline::finders::size::SizeFinder;
+use crate::core::span_pipeline::finders::source_location::SourceLocationFinder;
+use crate::core::span_pipeline::finders::syslog::SyslogFinder;
 use crate::core::span_pipeline::finders::unix_path::UnixPathFinder;
 use crate::core::span_pipeline::finders::unix_process::UnixProcessFinder;
 use crate::core::span_pipeline::finders::url::UrlFinder;
 use crate::core::span_pipeline::finders::uuid::UuidFinder;
+use crate::core::span_pipeline::finders::windows_path::WindowsPathFinder;
+use crate::core::span_pipeline::line_rule::LineRule;
 use crate::core::span_pipeline::palette::Palette;
 use crate::core::span_pipeline::span::Finder;
 use std::borrow::Cow;
@@ -47,6 +60,15 @@ pub enum Error {
     /// A keyword set could not be compiled into a keyword searcher.
     #[error("Pattern error: {0}")]
     Pattern(#[from] aho_corasick::BuildError),
+
+    /// A custom regex's `groups` names a capture group the pattern lacks.
+    #[error("Regex `{regex}` has no capture group named `{group}`")]
+    UnknownGroup {
+        /// The regex pattern.
+        regex: String,
+        /// The group name it lacks.
+        group: String,
+    },
 }
 
 impl Highlighter {
@@ -54,11 +76,25 @@ impl Highlighter {
     pub const fn builder() -> HighlighterBuilder {
         HighlighterBuilder {
             finders: Vec::new(),
+            line_rules: Vec::new(),
+            deltas: None,
+            compose: false,
+            plain: false,
             palette: Palette::new(),
             first_error: None,
         }
     }
 
+    /// Shows the highlighter a line before it is highlighted. Call it for
+    /// every line in stream order, on one thread, ahead of highlighting in
+    /// parallel: styles handed out as things first appear, like each
+    /// container's color, then follow the stream instead of thread
+    /// scheduling. Lines never observed still highlight, with those styles
+    /// picked by hashing.
+    pub fn observe(&self, input: &str) {
+        self.inner.observe(input);
+    }
+
     /// Applies the configured highlights to the given input string.
     #[must_use]
     pub fn apply<'a>(&self, input: &'a str) -> Cow<'a, str> {
@@ -123,6 +159,10 @@ impl Default for Highlighter {
 #[must_use]
 pub struct HighlighterBuilder {
     finders: Vec<Box<dyn Finder>>,
+    line_rules: Vec<LineRule>,
+    deltas: Option<DeltaFinder>,
+    compose: bool,
+    plain: bool,
     palette: Palette,
     first_error: Option<Error>,
 }
@@ -176,6 +216,26 @@ impl HighlighterBuilder {
         self.add_finder(date_time);
         let date_dash = DateDashFinder::new(config, &mut self.palette);
         self.add_finder(date_dash);
+        let date_month = DateMonthFinder::new(config, &mut self.palette);
+        self.add_finder(date_month);
+        let date_compact = DateCompactFinder::new(config, &mut self.palette);
+        self.add_finder(date_compact);
+        self
+    }
+
+    /// Adds a highlighter for the time deltas `tspin --deltas` puts before each line,
+    /// in a column `width` bytes wide, trailing space included. The other
+    /// highlighters then skip the column, so patterns anchored at the start of
+    /// the line still match.
+    pub fn with_delta_highlighter(mut self, config: DeltaConfig, width: usize) -> Self {
+        self.deltas = Some(DeltaFinder::new(config, width, &mut self.palette));
+        self
+    }
+
+    /// Adds a highlighter for Unix epoch timestamps next to time-like keys.
+    pub fn with_epoch_highlighter(mut self, config: EpochConfig) -> Self {
+        let finder = EpochFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
         self
     }
 
@@ -193,6 +253,63 @@ impl HighlighterBuilder {
         self
     }
 
+    /// Adds a highlighter for HTTP status codes, styled by class.
+    pub fn with_http_status_highlighter(mut self, config: HttpStatusConfig) -> Self {
+        let finder = HttpStatusFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for syslog headers (RFC 3164 and RFC 5424).
+    pub fn with_syslog_highlighter(mut self, config: SyslogConfig) -> Self {
+        let finder = SyslogFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for container prefixes in multiplexed logs, giving
+    /// each container its own style.
+    pub fn with_container_highlighter(mut self, config: ContainerConfig) -> Self {
+        let finder = ContainerFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for Windows drive paths and UNC paths.
+    pub fn with_windows_path_highlighter(mut self, config: WindowsPathConfig) -> Self {
+        let finder = WindowsPathFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for source locations like `src/main.rs:42:7`.
+    pub fn with_source_location_highlighter(mut self, config: SourceLocationConfig) -> Self {
+        let finder = SourceLocationFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for MAC addresses.
+    pub fn with_mac_highlighter(mut self, config: MacConfig) -> Self {
+        let finder = MacFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for hex digests such as git SHAs and `sha256:` digests.
+    pub fn with_hash_highlighter(mut self, config: HashConfig) -> Self {
+        let finder = HashFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
+    /// Adds a highlighter for byte sizes and percentages.
+    pub fn with_size_highlighter(mut self, config: SizeConfig) -> Self {
+        let finder = SizeFinder::new(config, &mut self.palette);
+        self.add_finder(finder);
+        self
+    }
+
     /// Adds a highlighter for IPv4 addresses.
     pub fn with_ip_v4_highlighter(mut self, config: IpV4Config) -> Self {
         let finder = IpV4Finder::new(config, &mut self.palette);
@@ -223,11 +340,41 @@ impl HighlighterBuilder {
 
     /// Adds a highlighter using a custom regex pattern.
     pub fn with_regex_highlighter(mut self, config: RegexConfig) -> Self {
-        let finder = RegexFinder::new(&config.regex, config.style, &mut self.palette).map_err(Error::Regex);
+        let finder = RegexFinder::new(&config, &mut self.palette);
         self.try_add_finder(finder);
         self
     }
 
+    /// Adds a rule that styles whole lines. The first rule to select a line
+    /// wins, and the other highlights are drawn over its style. Each input to
+    /// [`Highlighter::apply`] counts as one line.
+    pub fn with_line_highlighter(mut self, config: LineConfig) -> Self {
+        if self.first_error.is_some() {
+            return self;
+        }
+        match LineRule::new(&config, &mut self.palette) {
+            Ok(rule) => self.line_rules.push(rule),
+            Err(e) => self.first_error = Some(Error::Regex(e)),
+        }
+        self
+    }
+
+    /// Composes styles where highlights overlap. By default the highlighter
+    /// registered first takes the overlap whole; with composition, the others
+    /// show through wherever it leaves a color unset, and their attributes
+    /// (bold, italic, ...) still apply. A line style lies under every span.
+    pub const fn with_style_composition(mut self) -> Self {
+        self.compose = true;
+        self
+    }
+
+    /// Writes no styles: the input comes out unchanged apart from the text
+    /// highlighters add, like the decoded time after an epoch.
+    pub const fn with_plain_output(mut self) -> Self {
+        self.plain = true;
+        self
+    }
+
     /// Adds a highlighter for quoted text.
     pub fn with_quote_highlighter(mut self, config: QuoteConfig) -> Self {
         let finder = QuoteFinder::new(config, &mut self.palette);
@@ -255,7 +402,11 @@ impl HighlighterBuilder {
             Err(err)
         } else {
             Ok(Highlighter {
-                inner: Pipeline::new(self.finders, self.palette),
+                inner: Pipeline::new(self.finders, self.palette)
+                    .with_line_rules(self.line_rules)
+                    .with_prefix(self.deltas)
+                    .with_composition(self.compose)
+                    .with_plain_output(self.plain),
             })
         }
     }
@@ -285,6 +436,7 @@ mod tests {
         Highlighter::builder()
             .with_number_highlighter(NumberConfig {
                 style: Style::new().fg(Color::Cyan),
+                ..NumberConfig::default()
             })
             .with_quote_highlighter(QuoteConfig {
                 quote_token: b'"',
@@ -368,12 +520,105 @@ mod tests {
         assert_eq!(actual.to_string().convert_escape_codes(), expected);
     }
 
+    #[test]
+    fn first_selecting_line_rule_lies_under_the_highlights() {
+        let highlighter = Highlighter::builder()
+            .with_number_highlighter(NumberConfig {
+                style: Style::new().fg(Color::Cyan),
+                ..NumberConfig::default()
+            })
+            .with_line_highlighter(LineConfig {
+                words: vec!["ERROR".to_string()],
+                style: Style::new().on(Color::Red),
+                ..LineConfig::default()
+            })
+            .with_line_highlighter(LineConfig {
+                words: vec!["ERROR".to_string()],
+                invert: true,
+                style: Style::new().on(Color::Yellow),
+                ..LineConfig::default()
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            highlighter.apply("ERROR code 42").to_string().convert_escape_codes(),
+            "[bg_red]ERROR code [cyan]42[reset]"
+        );
+        assert_eq!(
+            highlighter.apply("INFO ok").to_string().convert_escape_codes(),
+            "[bg_yellow]INFO ok[reset]"
+        );
+    }
+
+    #[test]
+    fn composition_layers_overlapping_styles() {
+        let highlighter = Highlighter::builder()
+            .with_number_highlighter(NumberConfig {
+                style: Style::new().fg(Color::Cyan),
+                ..NumberConfig::default()
+            })
+            .with_quote_highlighter(QuoteConfig {
+                quote_token: b'"',
+                style: Style::new().fg(Color::Yellow).italic(),
+            })
+            .with_line_highlighter(LineConfig {
+                words: vec!["ERROR".to_string()],
+                style: Style::new().on(Color::Red),
+                ..LineConfig::default()
+            })
+            .with_style_composition()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            highlighter.apply(r#""a 42""#),
+            "\x1b[3;33m\"a \x1b[0m\x1b[3;36m42\x1b[0m\x1b[3;33m\"\x1b[0m"
+        );
+        assert_eq!(
+            highlighter.apply("ERROR 42"),
+            "\x1b[41mERROR \x1b[0m\x1b[41;36m42\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn plain_output_keeps_annotations_without_styles() {
+        let highlighter = Highlighter::builder()
+            .with_epoch_highlighter(EpochConfig {
+                annotate: true,
+                ..EpochConfig::default()
+            })
+            .with_number_highlighter(NumberConfig::default())
+            .with_plain_output()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            highlighter.apply("ts=1729245600 took 42"),
+            "ts=1729245600 (2024-10-18T10:00:00.000Z) took 42"
+        );
+        assert!(matches!(highlighter.apply("took 42"), Cow::Borrowed("took 42")));
+    }
+
+    #[test]
+    fn invalid_line_regex_fails_build() {
+        let result = Highlighter::builder()
+            .with_line_highlighter(LineConfig {
+                regex: Some("(unclosed".to_string()),
+                ..LineConfig::default()
+            })
+            .build();
+
+        assert!(matches!(result, Err(Error::Regex(_))));
+    }
+
     #[test]
     fn invalid_regex_fails_build_and_first_error_wins() {
         let result = Highlighter::builder()
             .with_regex_highlighter(RegexConfig {
                 regex: "(unclosed".to_string(),
                 style: Style::default(),
+                ..RegexConfig::default()
             })
             .with_keyword_highlighters(vec![kw(&["ok"], Style::default())])
             .build();
@@ -385,6 +630,7 @@ mod tests {
         KeywordConfig {
             words: words.iter().map(ToString::to_string).collect(),
             style,
+            ..KeywordConfig::default()
         }
     }
 }
@@ -3,6 +3,7 @@ pub mod style;
 
 pub mod config;
 pub(crate) mod span_pipeline;
+pub mod time;
 
 #[cfg(test)]
 mod tests {
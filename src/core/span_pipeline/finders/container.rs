@@ -0,0 +1,322 @@
+use super::build_regex;
+use memchr::memchr;
+use regex::Regex;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::core::config::ContainerConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Styles the per-container prefixes of multiplexed logs:
+/// `[pod/name/container]` from `kubectl logs --prefix` and `name  | ` from
+/// `docker compose logs`. Containers observed in stream order get the name
+/// styles in the order they appear, so no two share one until all are taken;
+/// any other container's style is picked by hashing its name. Either way a
+/// container keeps its style on every line, across parallel workers and
+/// across runs.
+#[derive(Debug, Clone)]
+pub(crate) struct ContainerFinder {
+    kubectl: Regex,
+    compose: Regex,
+    names: Vec<StyleId>,
+    separator: StyleId,
+    /// Shared by clones, so every worker sees the same assignments.
+    assigned: Arc<Assigned>,
+}
+
+/// Slot `i` holds the hash of the container styled with `names[i]`, 0 while
+/// free. Only `observe` claims slots, one compare-exchange per new container;
+/// highlighting just loads them, so the workers never wait on each other.
+#[derive(Debug)]
+struct Assigned {
+    slots: Box<[AtomicU64]>,
+}
+
+impl Assigned {
+    fn claim(&self, hash: u64) {
+        for slot in &self.slots {
+            match slot.compare_exchange(0, hash, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => return,
+                Err(taken) if taken == hash => return,
+                Err(_) => {}
+            }
+        }
+    }
+
+    fn position(&self, hash: u64) -> Option<usize> {
+        self.slots.iter().position(|slot| slot.load(Ordering::Acquire) == hash)
+    }
+}
+
+impl ContainerFinder {
+    pub fn new(config: ContainerConfig, palette: &mut Palette) -> Self {
+        // Pod and container names are DNS labels and subdomains.
+        let kubectl_pattern = r"(?xm)
+            ^
+            (?P<open>\[) pod/
+            (?P<pod>[a-z0-9][a-z0-9.-]*)
+            (?P<slash>/)
+            (?P<container>[a-z0-9][a-z0-9-]*)
+            (?P<close>])
+            \x20
+        ";
+        let kubectl = build_regex(kubectl_pattern);
+
+        // Compose pads every name to the longest one, so at least one space
+        // precedes the bar.
+        let compose_pattern = r"(?xm)
+            ^
+            (?P<name>[A-Za-z0-9][\w.-]*)
+            \x20+
+            (?P<bar>\|)
+            (?:\x20|$)
+        ";
+        let compose = build_regex(compose_pattern);
+
+        let slots = config.names.iter().map(|_| AtomicU64::new(0)).collect();
+        Self {
+            kubectl,
+            compose,
+            names: config.names.into_iter().map(|style| palette.intern(style)).collect(),
+            separator: palette.intern(config.separator),
+            assigned: Arc::new(Assigned { slots }),
+        }
+    }
+
+    /// The style for a container, or `None` when no name styles are configured.
+    fn name_style(&self, name: &str) -> Option<StyleId> {
+        let hash = name_hash(name);
+        let index = match self.assigned.position(hash) {
+            Some(index) => index,
+            None => usize::try_from(hash % self.names.len().max(1) as u64).ok()?,
+        };
+
+        self.names.get(index).copied()
+    }
+
+    /// Calls `f` with each container name on the line: `pod/container` for
+    /// kubectl, the service name for compose.
+    fn for_each_name(&self, input: &str, mut f: impl FnMut(&str)) {
+        let bytes = input.as_bytes();
+        if memchr(b'[', bytes).is_some() {
+            for caps in self.kubectl.captures_iter(input) {
+                let (pod, container) = (caps.name("pod").unwrap(), caps.name("container").unwrap());
+                f(&input[pod.start()..container.end()]);
+            }
+        }
+        if memchr(b'|', bytes).is_some() {
+            for caps in self.compose.captures_iter(input) {
+                f(caps.name("name").unwrap().as_str());
+            }
+        }
+    }
+
+    fn push_name(start: usize, end: usize, style: Option<StyleId>, collector: &mut Collector) {
+        if let Some(style) = style {
+            collector.push(start, end, style);
+        }
+    }
+
+    fn find_kubectl(&self, input: &str, collector: &mut Collector) {
+        for caps in self.kubectl.captures_iter(input) {
+            let (pod, container) = (caps.name("pod").unwrap(), caps.name("container").unwrap());
+            let style = self.name_style(&input[pod.start()..container.end()]);
+
+            let (open, slash, close) = (
+                caps.name("open").unwrap(),
+                caps.name("slash").unwrap(),
+                caps.name("close").unwrap(),
+            );
+
+            // `[pod/` is one separator run, the leading `[` and the `pod/` kind
+            collector.push(open.start(), pod.start(), self.separator);
+            Self::push_name(pod.start(), pod.end(), style, collector);
+            collector.push(slash.start(), slash.end(), self.separator);
+            Self::push_name(container.start(), container.end(), style, collector);
+            collector.push(close.start(), close.end(), self.separator);
+        }
+    }
+
+    fn find_compose(&self, input: &str, collector: &mut Collector) {
+        for caps in self.compose.captures_iter(input) {
+            let (name, bar) = (caps.name("name").unwrap(), caps.name("bar").unwrap());
+
+            Self::push_name(name.start(), name.end(), self.name_style(name.as_str()), collector);
+            collector.push(bar.start(), bar.end(), self.separator);
+        }
+    }
+}
+
+impl Finder for ContainerFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+
+        if memchr(b'[', bytes).is_some() {
+            self.find_kubectl(input, collector);
+        }
+        if memchr(b'|', bytes).is_some() {
+            self.find_compose(input, collector);
+        }
+    }
+
+    fn observe(&self, input: &str) {
+        self.for_each_name(input, |name| self.assigned.claim(name_hash(name)));
+    }
+}
+
+/// Never 0, which marks a free slot.
+fn name_hash(name: &str) -> u64 {
+    fnv1a(name.as_bytes()).max(1)
+}
+
+/// 64-bit FNV-1a: tiny, and unlike `std`'s hasher its output is fixed across
+/// runs and platforms.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+    use std::collections::HashSet;
+
+    fn config() -> ContainerConfig {
+        ContainerConfig {
+            names: vec![
+                Style::new().fg(Color::Cyan),
+                Style::new().fg(Color::Magenta),
+                Style::new().fg(Color::Green),
+                Style::new().fg(Color::Yellow),
+            ],
+            separator: Style::new().faint(),
+        }
+    }
+
+    fn make_finder() -> ContainerFinder {
+        ContainerFinder::new(config(), &mut Palette::new())
+    }
+
+    fn styles(finder: &ContainerFinder, input: &str) -> Vec<StyleId> {
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+
+        collector.into_spans().iter().map(|span| span.style).collect()
+    }
+
+    #[test]
+    fn kubectl_prefix() {
+        let texts = span_texts("[pod/web-7d4b9c-xk2p/nginx] GET /healthz 200", &make_finder());
+        assert_eq!(texts, ["[pod/", "web-7d4b9c-xk2p", "/", "nginx", "]"]);
+    }
+
+    #[test]
+    fn compose_prefix() {
+        assert_eq!(
+            span_texts("api-1  | listening on :8080", &make_finder()),
+            ["api-1", "|"]
+        );
+        assert_eq!(span_texts("db_1 | ready", &make_finder()), ["db_1", "|"]);
+    }
+
+    #[test]
+    fn same_container_keeps_its_style_across_lines() {
+        let finder = make_finder();
+
+        let first = styles(&finder, "web-1  | started");
+        let second = styles(&finder, "web-1  | GET / 200");
+        assert_eq!(first[0], second[0]);
+    }
+
+    const NAMES: [&str; 6] = ["api-1", "web-1", "db-1", "worker-1", "cache-1", "proxy-1"];
+
+    fn observe_all(finder: &ContainerFinder, names: &[&str]) {
+        for name in names {
+            finder.observe(&format!("{name}  | hi"));
+        }
+    }
+
+    fn name_styles(finder: &ContainerFinder, names: &[&str]) -> Vec<StyleId> {
+        names
+            .iter()
+            .map(|name| styles(finder, &format!("{name}  | hi"))[0])
+            .collect()
+    }
+
+    #[test]
+    fn observed_containers_get_distinct_styles_in_order() {
+        let mut palette = Palette::new();
+        let finder = ContainerFinder::new(ContainerConfig::default(), &mut palette);
+        observe_all(&finder, &NAMES);
+
+        let seen = name_styles(&finder, &NAMES);
+        assert_eq!(seen.iter().collect::<HashSet<_>>().len(), 6);
+        assert_eq!(seen[0], palette.intern(Style::new().fg(Color::Cyan)));
+        assert_eq!(seen[1], palette.intern(Style::new().fg(Color::Magenta)));
+    }
+
+    #[test]
+    fn containers_beyond_the_styles_fall_back_to_the_hash() {
+        let finder = make_finder();
+        observe_all(&finder, &NAMES);
+
+        let first = name_styles(&finder, &NAMES);
+        assert_eq!(first[..4].iter().collect::<HashSet<_>>().len(), 4);
+        assert_eq!(first, name_styles(&finder, &NAMES));
+
+        // Unobserved, the same container always hashes to the same style.
+        let unobserved = make_finder();
+        assert_eq!(name_styles(&unobserved, &NAMES), name_styles(&make_finder(), &NAMES));
+    }
+
+    #[test]
+    fn clones_share_the_assignments() {
+        let finder = make_finder();
+        let clone = finder.clone();
+
+        finder.observe("web-1  | started");
+        clone.observe("api-1  | started");
+        assert_eq!(
+            name_styles(&clone, &["web-1", "api-1"]),
+            name_styles(&finder, &["web-1", "api-1"])
+        );
+        assert_ne!(styles(&finder, "web-1  | a")[0], styles(&finder, "api-1  | a")[0]);
+    }
+
+    #[test]
+    fn pod_and_container_share_a_style() {
+        let finder = make_finder();
+
+        let found = styles(&finder, "[pod/web-0/app] hi");
+        assert_eq!(found[1], found[3]);
+    }
+
+    #[test]
+    fn no_name_styles_still_styles_separators() {
+        let finder = ContainerFinder::new(
+            ContainerConfig {
+                names: Vec::new(),
+                separator: Style::new().faint(),
+            },
+            &mut Palette::new(),
+        );
+        assert_eq!(span_texts("web-1  | hi", &finder), ["|"]);
+    }
+
+    #[test]
+    fn prefix_must_start_the_line() {
+        assert!(span_texts("seen [pod/web-0/app] hi", &make_finder()).is_empty());
+        assert!(span_texts("grep foo | wc -l", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn plain_log_line_no_match() {
+        assert!(span_texts("2024-09-14T07:57:30.659Z INFO Starting server", &make_finder()).is_empty());
+        assert!(span_texts("[INFO] pipe|delimited", &make_finder()).is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+use super::build_regex;
+use memchr::memchr;
+use regex::{Captures, Regex};
+
+use crate::core::config::DateTimeConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Styles ISO 8601 basic-format timestamps such as `20261018T140211Z` or
+/// `20261018T140211.153+0200`. A bare `20261018` is left to the number
+/// finder: without the `T` it is indistinguishable from a number.
+#[derive(Debug, Clone)]
+pub(crate) struct DateCompactFinder {
+    regex: Regex,
+    date: StyleId,
+    time: StyleId,
+    zone: StyleId,
+    separator: StyleId,
+}
+
+impl DateCompactFinder {
+    pub fn new(config: DateTimeConfig, palette: &mut Palette) -> Self {
+        let pattern = r"(?x)
+            \b
+            (?P<date> (?:19|20)\d{2} (?:0[1-9]|1[0-2]) (?:0[1-9]|[12]\d|3[01]) )
+            (?P<t>T)
+            (?P<time> (?:[01]\d|2[0-3]) [0-5]\d [0-5]\d )
+            (?: (?P<fraction_sep>[.,]) (?P<fraction>\d+) )?
+            (?P<zone> Z | [+-]\d{4} )?
+            \b
+        ";
+
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            date: palette.intern(config.date),
+            time: palette.intern(config.time),
+            zone: palette.intern(config.zone),
+            separator: palette.intern(config.separator),
+        }
+    }
+
+    fn push(caps: &Captures, name: &str, style: StyleId, collector: &mut Collector) {
+        if let Some(m) = caps.name(name) {
+            collector.push(m.start(), m.end(), style);
+        }
+    }
+}
+
+impl Finder for DateCompactFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if memchr(b'T', input.as_bytes()).is_none() {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            Self::push(&caps, "date", self.date, collector);
+            Self::push(&caps, "t", self.zone, collector);
+            Self::push(&caps, "time", self.time, collector);
+            Self::push(&caps, "fraction_sep", self.separator, collector);
+            Self::push(&caps, "fraction", self.time, collector);
+            Self::push(&caps, "zone", self.zone, collector);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+
+    fn make_finder() -> DateCompactFinder {
+        DateCompactFinder::new(DateTimeConfig::default(), &mut Palette::new())
+    }
+
+    #[test]
+    fn basic_format_timestamps() {
+        assert_eq!(
+            span_texts("at 20261018T140211Z ok", &make_finder()),
+            ["20261018", "T", "140211", "Z"]
+        );
+        assert_eq!(
+            span_texts("20261018T140211.153+0200", &make_finder()),
+            ["20261018", "T", "140211", ".", "153", "+0200"]
+        );
+    }
+
+    #[test]
+    fn impossible_fields_no_match() {
+        assert!(span_texts("20261318T140211Z", &make_finder()).is_empty());
+        assert!(span_texts("20261018T250211Z", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn bare_dates_and_longer_runs_no_match() {
+        assert!(span_texts("20261018", &make_finder()).is_empty());
+        assert!(span_texts("120261018T140211Z", &make_finder()).is_empty());
+        assert!(span_texts("20261018T1402110", &make_finder()).is_empty());
+    }
+}
@@ -1,5 +1,5 @@
 use super::build_regex;
-use memchr::memchr2;
+use memchr::memchr3;
 use regex::Regex;
 
 use crate::core::config::DateTimeConfig;
@@ -7,6 +7,10 @@ use crate::core::config::DateTimeConfig;
 use super::super::palette::{Palette, StyleId};
 use super::super::span::{Collector, Finder};
 
+/// `YYYY-MM-DD`, `YYYY/MM/DD` or `YYYY.MM.DD`, the year-first branch below.
+/// Shared with [`TimestampScanner`](crate::core::time::TimestampScanner).
+pub(crate) const YEAR_FIRST_DATE: &str = r"(?:19\d{2}|20\d{2})[-/.](?:0[1-9]|[12]\d|3[01])[-/.](?:0[1-9]|[12]\d|3[01])";
+
 #[derive(Debug, Clone)]
 pub(crate) struct DateDashFinder {
     regex: Regex,
@@ -18,20 +22,22 @@ impl DateDashFinder {
     pub fn new(config: DateTimeConfig, palette: &mut Palette) -> Self {
         // Both branches are exactly 10 bytes (4+1+2+1+2), so we can use
         // find_iter and compute component offsets arithmetically.
-        let pattern = r"(?x)
+        let pattern = format!(
+            r"(?x)
             # Leading \b only: a trailing one would reject the `T` in ISO-8601
             # timestamps (2022-09-22T07:46:34), whose date half we highlight.
             \b
             (?:
                 # Branch A: YYYY-xx-xx
-                (?: (?: 19\d{2} | 20\d{2} ) [-/] (?: 0[1-9] | [12]\d | 3[01] ) [-/] (?: 0[1-9] | [12]\d | 3[01] ) )
+                {YEAR_FIRST_DATE}
                 |
                 # Branch B: xx-xx-YYYY
-                (?: (?: 0[1-9] | [12]\d | 3[01] ) [-/] (?: 0[1-9] | [12]\d | 3[01] ) [-/] (?: 19\d{2} | 20\d{2} ) )
+                (?: (?: 0[1-9] | [12]\d | 3[01] ) [-/.] (?: 0[1-9] | [12]\d | 3[01] ) [-/.] (?: 19\d{{2}} | 20\d{{2}} ) )
             )
-        ";
+        "
+        );
 
-        let regex = build_regex(pattern);
+        let regex = build_regex(&pattern);
 
         Self {
             regex,
@@ -43,7 +49,7 @@ impl DateDashFinder {
 
 impl Finder for DateDashFinder {
     fn find_spans(&self, input: &str, collector: &mut Collector) {
-        if memchr2(b'-', b'/', input.as_bytes()).is_none() {
+        if memchr3(b'-', b'/', b'.', input.as_bytes()).is_none() {
             return;
         }
 
@@ -54,7 +60,7 @@ impl Finder for DateDashFinder {
             // Both branches are exactly 10 bytes. Distinguish by checking
             // whether position 4 is a separator (Branch A: YYYY-MM-DD)
             // or position 2 is a separator (Branch B: MM-DD-YYYY).
-            if bytes[4] == b'-' || bytes[4] == b'/' {
+            if matches!(bytes[4], b'-' | b'/' | b'.') {
                 // Branch A: YYYY-MM-DD
                 collector.push(s, s + 4, self.date);
                 collector.push(s + 4, s + 5, self.separator);
@@ -114,6 +120,17 @@ mod tests {
         assert_eq!(texts, ["09", "/", "09", "/", "2022"]);
     }
 
+    #[test]
+    fn dotted_dates() {
+        assert_eq!(span_texts("2026.10.18", &make_finder()), ["2026", ".", "10", ".", "18"]);
+        assert_eq!(span_texts("18.10.2026", &make_finder()), ["18", ".", "10", ".", "2026"]);
+    }
+
+    #[test]
+    fn dotted_versions_no_match() {
+        assert!(span_texts("v1.10.18 and 10.0.0.1", &make_finder()).is_empty());
+    }
+
     #[test]
     fn invalid_year_no_match() {
         assert!(span_texts("3022-09-09", &make_finder()).is_empty());
@@ -0,0 +1,128 @@
+use super::build_regex;
+use regex::{Captures, Regex};
+
+use crate::core::config::DateTimeConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// English month abbreviations, as syslog and the common log format write them.
+const MONTH: &str = "Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec";
+
+/// Styles dates that name the month: the syslog `Oct 18 14:02:11` (the day
+/// padded with a space below 10) and the common log format's
+/// `18/Oct/2026:14:02:11 +0000`. The time itself is left to the time finder.
+#[derive(Debug, Clone)]
+pub(crate) struct DateMonthFinder {
+    regex: Regex,
+    date: StyleId,
+    zone: StyleId,
+    separator: StyleId,
+}
+
+impl DateMonthFinder {
+    pub fn new(config: DateTimeConfig, palette: &mut Palette) -> Self {
+        // A syslog date only counts when a time follows it, so prose such as
+        // "May 5" is left alone.
+        let pattern = format!(
+            r"(?x)
+            \b
+            (?:
+                # Branch A: Mon DD hh:mm:ss
+                (?P<month>{MONTH}) \x20\x20?
+                (?P<day>0?[1-9]|[12]\d|3[01]) \x20
+                \d{{1,2}}:\d{{2}}:\d{{2}}
+                |
+                # Branch B: DD/Mon/YYYY:hh:mm:ss +zzzz
+                (?P<clf_day>0[1-9]|[12]\d|3[01]) (?P<sep1>/)
+                (?P<clf_month>{MONTH}) (?P<sep2>/)
+                (?P<year>19\d{{2}}|20\d{{2}})
+                (?:
+                    (?P<sep3>:) \d{{2}}:\d{{2}}:\d{{2}}
+                    (?P<zone>\x20[+-]\d{{4}})?
+                )?
+            )
+            \b
+        "
+        );
+
+        let regex = build_regex(&pattern);
+
+        Self {
+            regex,
+            date: palette.intern(config.date),
+            zone: palette.intern(config.zone),
+            separator: palette.intern(config.separator),
+        }
+    }
+
+    fn push(caps: &Captures, name: &str, style: StyleId, collector: &mut Collector) {
+        if let Some(m) = caps.name(name) {
+            collector.push(m.start(), m.end(), style);
+        }
+    }
+}
+
+impl Finder for DateMonthFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        for caps in self.regex.captures_iter(input) {
+            if caps.name("month").is_some() {
+                Self::push(&caps, "month", self.date, collector);
+                Self::push(&caps, "day", self.date, collector);
+            } else {
+                Self::push(&caps, "clf_day", self.date, collector);
+                Self::push(&caps, "sep1", self.separator, collector);
+                Self::push(&caps, "clf_month", self.date, collector);
+                Self::push(&caps, "sep2", self.separator, collector);
+                Self::push(&caps, "year", self.date, collector);
+                Self::push(&caps, "sep3", self.separator, collector);
+                Self::push(&caps, "zone", self.zone, collector);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+
+    fn make_finder() -> DateMonthFinder {
+        DateMonthFinder::new(DateTimeConfig::default(), &mut Palette::new())
+    }
+
+    #[test]
+    fn syslog_dates() {
+        assert_eq!(span_texts("Oct 18 14:02:11 host app:", &make_finder()), ["Oct", "18"]);
+        assert_eq!(span_texts("Oct  8 14:02:11 host app:", &make_finder()), ["Oct", "8"]);
+    }
+
+    #[test]
+    fn syslog_dates_need_a_time() {
+        assert!(span_texts("shipped in May 5 days late", &make_finder()).is_empty());
+        assert!(span_texts("Oct 18", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn common_log_format_dates() {
+        let texts = span_texts(
+            r#"127.0.0.1 - - [18/Oct/2026:14:02:11 +0000] "GET / HTTP/1.1" 200"#,
+            &make_finder(),
+        );
+        assert_eq!(texts, ["18", "/", "Oct", "/", "2026", ":", " +0000"]);
+    }
+
+    #[test]
+    fn common_log_format_date_alone() {
+        assert_eq!(
+            span_texts("on 18/Oct/2026.", &make_finder()),
+            ["18", "/", "Oct", "/", "2026"]
+        );
+    }
+
+    #[test]
+    fn month_must_be_a_whole_word() {
+        assert!(span_texts("XOct 18 14:02:11", &make_finder()).is_empty());
+        assert!(span_texts("118/Oct/2026", &make_finder()).is_empty());
+    }
+}
@@ -7,6 +7,10 @@ use crate::core::config::DateTimeConfig;
 use super::super::palette::{Palette, StyleId};
 use super::super::span::{Collector, Finder};
 
+/// `H?H:MM:SS` with an optional fraction of a second. Shared with
+/// [`TimestampScanner`](crate::core::time::TimestampScanner).
+pub(crate) const CLOCK_TIME: &str = r"(?:[01]?\d|2[0-3]):[0-5]\d:[0-5]\d(?:[.,:]\d+)?";
+
 #[derive(Debug, Clone)]
 pub(crate) struct DateTimeFinder {
     regex: Regex,
@@ -19,16 +23,17 @@ impl DateTimeFinder {
     pub fn new(config: DateTimeConfig, palette: &mut Palette) -> Self {
         // Match structure: [T| ]? H?H:MM:SS [.,:]digits? Z?
         // We use find_iter and parse the fixed structure from match bytes.
-        let pattern = r"(?x)
-            [T\s]?
-            (?:[01]?\d|2[0-3]):
-            [0-5]\d:
-            [0-5]\d
-            (?:[.,:]  \d+)?
+        // Without a T or space the hour must start a word, so the `2026:14:02:11`
+        // of the common log format yields `14:02:11`, not `6:14:02:11`.
+        let pattern = format!(
+            r"(?x)
+            (?:[T\s]|\b)
+            {CLOCK_TIME}
             Z?
-        ";
+        "
+        );
 
-        let regex = build_regex(pattern);
+        let regex = build_regex(&pattern);
 
         Self {
             regex,
@@ -162,6 +167,12 @@ mod tests {
         assert!(texts.contains(&"659"));
     }
 
+    #[test]
+    fn time_after_common_log_format_year() {
+        let texts = span_texts("18/Oct/2026:14:02:11 +0000", &make_finder());
+        assert_eq!(texts, ["14", ":", "02", ":", "11"]);
+    }
+
     #[test]
     fn ipv6_should_not_match_as_time() {
         // IPv6 addresses contain colons but should not be matched by DateTime
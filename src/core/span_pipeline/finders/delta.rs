@@ -0,0 +1,131 @@
+use crate::core::config::{DeltaConfig, TimeSpan};
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Styles the delta `tspin --deltas` puts at the start of a line (`+0.153s`,
+/// `-2.000s`, `+43m`), switching to the warn style once its magnitude reaches
+/// the threshold. The column, delta or blank, has a fixed width and is the
+/// line's prefix: the pipeline runs the other finders on what follows it, so
+/// `^`-anchored formats like JSON or container prefixes still match.
+#[derive(Debug, Clone)]
+pub(crate) struct DeltaFinder {
+    width: usize,
+    style: StyleId,
+    warn: Option<TimeSpan>,
+    warn_style: StyleId,
+}
+
+impl DeltaFinder {
+    pub fn new(config: DeltaConfig, width: usize, palette: &mut Palette) -> Self {
+        Self {
+            width,
+            style: palette.intern(config.style),
+            warn: config.warn,
+            warn_style: palette.intern(config.warn_style),
+        }
+    }
+
+    /// The length of the delta column at the start of `input`, 0 if the line
+    /// is too short to hold one.
+    pub fn prefix_len(&self, input: &str) -> usize {
+        if input.is_char_boundary(self.width) {
+            self.width
+        } else {
+            0
+        }
+    }
+}
+
+impl Finder for DeltaFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let column = &input[..self.prefix_len(input)];
+        let delta = column.trim();
+        let Some(magnitude) = delta.strip_prefix(['+', '-']) else {
+            return;
+        };
+
+        let split = magnitude
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(magnitude.len());
+        let (value, unit) = magnitude.split_at(split);
+        let Some(span) = value
+            .parse::<f64>()
+            .ok()
+            .and_then(|value| TimeSpan::from_value(value, unit))
+        else {
+            return;
+        };
+
+        let style = if self.warn.is_some_and(|warn| span >= warn) {
+            self.warn_style
+        } else {
+            self.style
+        };
+        let start = column.len() - column.trim_start().len();
+        collector.push(start, start + delta.len(), style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn config() -> DeltaConfig {
+        DeltaConfig {
+            style: Style::new().faint(),
+            warn_style: Style::new().fg(Color::Yellow),
+            warn: TimeSpan::from_value(1.0, "s"),
+        }
+    }
+
+    fn finder(palette: &mut Palette) -> DeltaFinder {
+        DeltaFinder::new(config(), 10, palette)
+    }
+
+    fn style(finder: &DeltaFinder, input: &str) -> StyleId {
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+
+        collector.into_spans()[0].style
+    }
+
+    #[test]
+    fn finds_the_leading_delta() {
+        let finder = finder(&mut Palette::new());
+        assert_eq!(span_texts("  +0.153s 2024-10-18 ready", &finder), ["+0.153s"]);
+        assert_eq!(span_texts("  -2.000s later", &finder), ["-2.000s"]);
+        assert_eq!(span_texts("     +43m later", &finder), ["+43m"]);
+    }
+
+    #[test]
+    fn only_looks_in_the_column() {
+        let finder = finder(&mut Palette::new());
+        assert!(span_texts("          took +0.153s to boot", &finder).is_empty());
+        assert!(span_texts("          +0.153s later", &finder).is_empty());
+        assert!(span_texts("+1", &finder).is_empty());
+    }
+
+    #[test]
+    fn prefix_is_the_column_width() {
+        let finder = finder(&mut Palette::new());
+        assert_eq!(finder.prefix_len("  +0.153s {\"a\":1}"), 10);
+        assert_eq!(finder.prefix_len("+1.234s 2024-10-18 starts like a delta"), 10);
+        assert_eq!(finder.prefix_len("short"), 0);
+    }
+
+    #[test]
+    fn slow_deltas_either_way_warn() {
+        let mut palette = Palette::new();
+        let finder = finder(&mut palette);
+        let warn = palette.intern(Style::new().fg(Color::Yellow));
+        let plain = palette.intern(Style::new().faint());
+
+        assert_eq!(style(&finder, "  +0.999s a"), plain);
+        assert_eq!(style(&finder, "  +1.000s a"), warn);
+        assert_eq!(style(&finder, " -12.500s a"), warn);
+        assert_eq!(style(&finder, "     +43m a"), warn);
+    }
+}
@@ -1,7 +1,7 @@
-use super::build_regex;
+use super::{Thresholds, build_regex};
 use regex::Regex;
 
-use crate::core::config::DurationConfig;
+use crate::core::config::{DurationConfig, TimeSpan};
 
 use super::super::palette::{Palette, StyleId};
 use super::super::span::{Collector, Finder};
@@ -12,6 +12,7 @@ pub(crate) struct DurationFinder {
     value: StyleId,
     separator: StyleId,
     unit: StyleId,
+    thresholds: Thresholds<TimeSpan>,
 }
 
 impl DurationFinder {
@@ -31,6 +32,11 @@ impl DurationFinder {
             value: palette.intern(config.value),
             separator: palette.intern(config.separator),
             unit: palette.intern(config.unit),
+            thresholds: Thresholds::new(
+                (config.warn, config.error),
+                (config.warn_style, config.error_style),
+                palette,
+            ),
         }
     }
 }
@@ -44,7 +50,22 @@ impl Finder for DurationFinder {
         }
 
         for caps in self.regex.captures_iter(input) {
-            let integer = caps.name("integer").unwrap();
+            let (whole, integer, unit) = (
+                caps.get(0).unwrap(),
+                caps.name("integer").unwrap(),
+                caps.name("unit").unwrap(),
+            );
+
+            let exceeded = input[integer.start()..unit.start()]
+                .parse::<f64>()
+                .ok()
+                .and_then(|value| TimeSpan::from_value(value, unit.as_str()))
+                .and_then(|span| self.thresholds.style_for(&span));
+            if let Some(style) = exceeded {
+                collector.push(whole.start(), whole.end(), style);
+                continue;
+            }
+
             collector.push(integer.start(), integer.end(), self.value);
 
             if let Some(dot) = caps.name("dot") {
@@ -53,7 +74,6 @@ impl Finder for DurationFinder {
                 collector.push(fraction.start(), fraction.end(), self.value);
             }
 
-            let unit = caps.name("unit").unwrap();
             collector.push(unit.start(), unit.end(), self.unit);
         }
     }
@@ -71,6 +91,7 @@ mod tests {
                 value: Style::new().fg(Color::Cyan),
                 separator: Style::new().fg(Color::Red),
                 unit: Style::new().fg(Color::Magenta),
+                ..DurationConfig::default()
             },
             &mut Palette::new(),
         )
@@ -88,6 +109,31 @@ mod tests {
         assert_eq!(texts, ["5", "ns", "5", "us", "5", "ms", "5", "s", "5", "m", "5", "h"]);
     }
 
+    #[test]
+    fn slow_durations_are_styled_as_a_whole() {
+        let mut palette = Palette::new();
+        let finder = DurationFinder::new(
+            DurationConfig {
+                warn_style: Style::new().fg(Color::Yellow),
+                error_style: Style::new().fg(Color::Red),
+                warn: TimeSpan::from_value(500.0, "ms"),
+                error: TimeSpan::from_value(5.0, "s"),
+                ..DurationConfig::default()
+            },
+            &mut palette,
+        );
+        let warn = palette.intern(Style::new().fg(Color::Yellow));
+        let error = palette.intern(Style::new().fg(Color::Red));
+
+        let input = "took 2ms, 0.5s, 750ms and 1.2m";
+        assert_eq!(span_texts(input, &finder), ["2", "ms", "0.5s", "750ms", "1.2m"]);
+
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+        let styles: Vec<StyleId> = collector.into_spans().iter().map(|span| span.style).collect();
+        assert_eq!(styles[2..], [warn, warn, error]);
+    }
+
     #[test]
     fn plain_numbers_are_not_durations() {
         assert!(span_texts("status 200 port 8080", &make_finder()).is_empty());
@@ -0,0 +1,146 @@
+use super::build_regex;
+use memchr::memchr2;
+use regex::Regex;
+
+use crate::core::config::EpochConfig;
+use crate::core::time::Timestamp;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Annotation, Collector, Finder};
+
+/// Styles Unix epochs given as the value of a time-like key (`ts`, `time`,
+/// `timestamp`, `epoch`, `*_at`, `*_ts`, `*_time`). The digit count gives the
+/// unit: 10 for seconds, 13 for milliseconds, 16 for microseconds and 19 for
+/// nanoseconds, so only epochs from 2001 to 2286 are recognized. Bare numbers
+/// are left to the number finder.
+#[derive(Debug, Clone)]
+pub(crate) struct EpochFinder {
+    regex: Regex,
+    epoch: StyleId,
+    annotation: Option<Annotation>,
+}
+
+impl EpochFinder {
+    pub fn new(config: EpochConfig, palette: &mut Palette) -> Self {
+        let pattern = r#"(?xi)
+            \b
+            (?:[a-z0-9_]*_)?                        # optional key prefix, e.g. `created_`
+            (?:ts|time|timestamp|epoch|at)          # time-like key
+            (?:_?(?:s|secs|ms|millis|us|micros|ns|nanos))?  # optional unit suffix
+            "?\s*[:=]\s*"?
+            (?P<epoch>\d{10}(?:\d{3}){0,3}(?:\.\d{1,9})?)
+            \b
+        "#;
+        let regex = build_regex(pattern);
+        let date_time = config.date_time;
+
+        Self {
+            regex,
+            epoch: palette.intern(date_time.date),
+            annotation: config
+                .annotate
+                .then(|| Annotation::Epoch(palette.intern(date_time.separator))),
+        }
+    }
+}
+
+impl Finder for EpochFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        // Every key is followed by `:` or `=`.
+        if memchr2(b':', b'=', input.as_bytes()).is_none() {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            let epoch = caps.name("epoch").unwrap();
+            match self.annotation {
+                Some(annotation) => collector.push_annotated(epoch.start(), epoch.end(), self.epoch, annotation),
+                None => collector.push(epoch.start(), epoch.end(), self.epoch),
+            }
+        }
+    }
+}
+
+/// Decodes an epoch as matched by [`EpochFinder`]: 10, 13, 16 or 19 integer
+/// digits for seconds through nanoseconds, with an optional fraction of that
+/// unit.
+pub(crate) fn decode(epoch: &str) -> Option<Timestamp> {
+    let (integer, fraction) = epoch.split_once('.').unwrap_or((epoch, ""));
+    let unit_nanos = match integer.len() {
+        10 => 1_000_000_000,
+        13 => 1_000_000,
+        16 => 1_000,
+        19 => 1,
+        _ => return None,
+    };
+
+    let mut nanos = integer.parse::<u128>().ok()? * unit_nanos;
+    if !fraction.is_empty() {
+        let scale = 10u128.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+        nanos += fraction.parse::<u128>().ok()? * unit_nanos / scale;
+    }
+
+    Timestamp::from_unix_nanos(nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+
+    fn make_finder() -> EpochFinder {
+        EpochFinder::new(EpochConfig::default(), &mut Palette::new())
+    }
+
+    #[test]
+    fn finds_epochs_after_time_keys() {
+        let texts = span_texts(
+            r#"{"ts":1729245600.123,"created_at": 1729245600123} time=1729245600123456"#,
+            &make_finder(),
+        );
+        assert_eq!(texts, ["1729245600.123", "1729245600123", "1729245600123456"]);
+    }
+
+    #[test]
+    fn finds_keys_with_unit_suffixes() {
+        let texts = span_texts("timestamp_ms=1729245600123 @timestamp: 1729245600", &make_finder());
+        assert_eq!(texts, ["1729245600123", "1729245600"]);
+    }
+
+    #[test]
+    fn ignores_other_keys_and_lengths() {
+        assert!(span_texts("id=1729245600 format=1729245600", &make_finder()).is_empty());
+        assert!(span_texts("ts=172924560 ts=17292456001", &make_finder()).is_empty());
+        assert!(span_texts("took 1729245600 cycles", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn annotation_requires_annotate() {
+        let mut collector = Collector::new();
+        make_finder().find_spans("ts=1729245600", &mut collector);
+        assert_eq!(collector.into_spans()[0].annotation, None);
+
+        let finder = EpochFinder::new(
+            EpochConfig {
+                annotate: true,
+                ..EpochConfig::default()
+            },
+            &mut Palette::new(),
+        );
+        let mut collector = Collector::new();
+        finder.find_spans("ts=1729245600", &mut collector);
+        assert!(collector.into_spans()[0].annotation.is_some());
+    }
+
+    #[test]
+    fn decodes_every_unit() {
+        let decoded = |epoch| decode(epoch).unwrap().to_string();
+        assert_eq!(decoded("1729245600"), "2024-10-18T10:00:00.000Z");
+        assert_eq!(decoded("1729245600.123"), "2024-10-18T10:00:00.123Z");
+        assert_eq!(decoded("1729245600123"), "2024-10-18T10:00:00.123Z");
+        assert_eq!(decoded("1729245600123.9"), "2024-10-18T10:00:00.123Z");
+        assert_eq!(decoded("1729245600123456"), "2024-10-18T10:00:00.123Z");
+        assert_eq!(decoded("1729245600123456789"), "2024-10-18T10:00:00.123Z");
+        assert_eq!(decode("17292456001"), None);
+    }
+}
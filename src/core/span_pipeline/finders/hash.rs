@@ -0,0 +1,130 @@
+use super::build_regex;
+use regex::Regex;
+
+use crate::core::config::HashConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Styles hex digests as whole tokens: git SHAs (`a1b2c3d`), checksums and
+/// algorithm-prefixed digests like `sha256:9f86d0…`. Unprefixed tokens must
+/// mix digits and letters, so plain numbers and hex-looking words are left to
+/// the other finders.
+#[derive(Debug, Clone)]
+pub(crate) struct HashFinder {
+    regex: Regex,
+    min_length: usize,
+    prefix: StyleId,
+    digest: StyleId,
+}
+
+impl HashFinder {
+    pub fn new(config: HashConfig, palette: &mut Palette) -> Self {
+        // Any ASCII byte but a word character or `-`, spelled out because a
+        // negated class can match invalid UTF-8 in byte mode.
+        let delimiter = r"[\s!-,./:-@\[-^`{-~]";
+        let pattern = format!(
+            r"(?x)
+            (?:^|{delimiter})
+            (?P<prefix>(?:sha1|sha224|sha256|sha384|sha512|md5|blake2b|blake3):)?
+            (?P<digest>[0-9a-f]+|[0-9A-F]+)
+            (?:$|{delimiter})
+            "
+        );
+        let regex = build_regex(&pattern);
+
+        Self {
+            regex,
+            min_length: config.min_length,
+            prefix: palette.intern(config.prefix),
+            digest: palette.intern(config.digest),
+        }
+    }
+
+    fn is_digest(&self, digest: &str, prefixed: bool) -> bool {
+        let bytes = digest.as_bytes();
+        if prefixed {
+            return !bytes.is_empty();
+        }
+
+        bytes.len() >= self.min_length
+            && bytes.iter().any(u8::is_ascii_digit)
+            && bytes.iter().any(u8::is_ascii_alphabetic)
+    }
+}
+
+impl Finder for HashFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let mut pos = 0;
+
+        // The delimiters around a digest are consumed, so a match can end on
+        // the byte the next one needs; resume just before it.
+        while let Some(caps) = self.regex.captures_at(input, pos) {
+            let digest = caps.name("digest").unwrap();
+            let prefix = caps.name("prefix");
+
+            if self.is_digest(digest.as_str(), prefix.is_some()) {
+                if let Some(prefix) = prefix {
+                    collector.push(prefix.start(), prefix.end(), self.prefix);
+                }
+                collector.push(digest.start(), digest.end(), self.digest);
+            }
+
+            pos = digest.end();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> HashFinder {
+        HashFinder::new(
+            HashConfig {
+                prefix: Style::new().faint(),
+                digest: Style::new().fg(Color::Yellow),
+                min_length: 7,
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn short_git_sha() {
+        assert_eq!(span_texts("deployed a1b2c3d to prod", &make_finder()), ["a1b2c3d"]);
+    }
+
+    #[test]
+    fn prefixed_digest() {
+        let texts = span_texts("image@sha256:9f86d081884c7d65 pulled", &make_finder());
+        assert_eq!(texts, ["sha256:", "9f86d081884c7d65"]);
+    }
+
+    #[test]
+    fn adjacent_digests() {
+        let texts = span_texts("a1b2c3d e4f5a6b", &make_finder());
+        assert_eq!(texts, ["a1b2c3d", "e4f5a6b"]);
+    }
+
+    #[test]
+    fn below_min_length_no_match() {
+        assert!(span_texts("ref a1b2c3", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn numbers_and_words_no_match() {
+        assert!(span_texts("took 12345678 cycles", &make_finder()).is_empty());
+        assert!(span_texts("the cafe was defaced", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn parts_of_larger_tokens_no_match() {
+        assert!(span_texts("550e8400-e29b-41d4-a716-446655440000", &make_finder()).is_empty());
+        assert!(span_texts("ptr 0x7fff5fbff8a0", &make_finder()).is_empty());
+        assert!(span_texts("build_a1b2c3d4", &make_finder()).is_empty());
+        assert!(span_texts("mixed a1B2c3D4e5", &make_finder()).is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+use super::build_regex;
+use regex::Regex;
+
+use crate::core::config::HttpStatusConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Only codes in an HTTP context are claimed: after a `status`/`code`-like
+/// key, an `HTTP/x.y` version, or an HTTP method and its request target.
+/// Everything else stays with the number finder.
+#[derive(Debug, Clone)]
+pub(crate) struct HttpStatusFinder {
+    regex: Regex,
+    classes: [StyleId; 5],
+}
+
+impl HttpStatusFinder {
+    pub fn new(config: HttpStatusConfig, palette: &mut Palette) -> Self {
+        let pattern = r#"(?x)
+            (?:
+                # status=503, "status": "404", statusCode: 200, responded 404
+                \b(?i:status(?:[_-]?code)?|code|responded|returned)
+                (?: "?\s*[:=]\s*"? | \s+(?i:with\s+)? )
+              |
+                # HTTP/1.1 301 Moved, "GET / HTTP/1.1" 200
+                \bHTTP/\d(?:\.\d)?"?\s+
+              |
+                # GET /api/users 200; the target is printable ASCII, since a
+                # byte-mode \S could match invalid UTF-8, which the str API rejects
+                \b(?:GET|HEAD|POST|PUT|PATCH|DELETE|OPTIONS|CONNECT|TRACE)\s+[!-~]+\s+
+            )
+            (?P<code>[1-5]\d{2})
+            \b
+        "#;
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            classes: [
+                palette.intern(config.informational),
+                palette.intern(config.success),
+                palette.intern(config.redirection),
+                palette.intern(config.client_error),
+                palette.intern(config.server_error),
+            ],
+        }
+    }
+}
+
+impl Finder for HttpStatusFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if !input.bytes().any(|b| b.is_ascii_digit()) {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            let code = caps.name("code").unwrap();
+            // The pattern pins the first digit to 1-5.
+            let class = usize::from(code.as_str().as_bytes()[0] - b'1');
+            collector.push(code.start(), code.end(), self.classes[class]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder(palette: &mut Palette) -> HttpStatusFinder {
+        HttpStatusFinder::new(
+            HttpStatusConfig {
+                informational: Style::new().fg(Color::Blue),
+                success: Style::new().fg(Color::Green),
+                redirection: Style::new().fg(Color::Cyan),
+                client_error: Style::new().fg(Color::Yellow),
+                server_error: Style::new().fg(Color::Red),
+            },
+            palette,
+        )
+    }
+
+    fn texts(input: &str) -> Vec<&str> {
+        span_texts(input, &make_finder(&mut Palette::new()))
+    }
+
+    #[test]
+    fn key_value_status() {
+        assert_eq!(texts("request failed status=503 retry"), ["503"]);
+        assert_eq!(texts("status_code: 201"), ["201"]);
+        assert_eq!(texts(r#"{"statusCode": "404"}"#), ["404"]);
+    }
+
+    #[test]
+    fn prose_status() {
+        assert_eq!(texts("upstream responded 404 in 12ms"), ["404"]);
+        assert_eq!(texts("handler returned with 500"), ["500"]);
+    }
+
+    #[test]
+    fn after_http_version() {
+        assert_eq!(texts("HTTP/1.1 301 Moved Permanently"), ["301"]);
+        assert_eq!(texts(r#""GET /index.html HTTP/1.1" 200 512"#), ["200"]);
+        assert_eq!(texts("HTTP/2 101 Switching Protocols"), ["101"]);
+    }
+
+    #[test]
+    fn after_method_and_target() {
+        assert_eq!(texts("GET /api/users 200 12ms"), ["200"]);
+        assert_eq!(texts("POST https://example.com/login 401"), ["401"]);
+    }
+
+    #[test]
+    fn each_class_gets_its_own_style() {
+        let mut palette = Palette::new();
+        let finder = make_finder(&mut palette);
+
+        for (code, color) in [
+            ("102", Color::Blue),
+            ("204", Color::Green),
+            ("304", Color::Cyan),
+            ("418", Color::Yellow),
+            ("599", Color::Red),
+        ] {
+            let mut collector = Collector::new();
+            finder.find_spans(&format!("status={code}"), &mut collector);
+
+            let spans = collector.into_spans();
+            assert_eq!(spans.len(), 1);
+            assert_eq!(spans[0].style, palette.intern(Style::new().fg(color)), "status={code}");
+        }
+    }
+
+    #[test]
+    fn numbers_outside_http_context_no_match() {
+        assert!(texts("count 200 items").is_empty());
+        assert!(texts("took 404ms").is_empty());
+    }
+
+    #[test]
+    fn out_of_range_codes_no_match() {
+        assert!(texts("status=600").is_empty());
+        assert!(texts("status=099").is_empty());
+        assert!(texts("status=2000").is_empty());
+    }
+
+    #[test]
+    fn key_must_be_a_whole_word() {
+        assert!(texts("barcode 200").is_empty());
+        assert!(texts("status200").is_empty());
+    }
+}
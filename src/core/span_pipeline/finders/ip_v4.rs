@@ -12,6 +12,8 @@ pub(crate) struct IpV4Finder {
     regex: Regex,
     number: StyleId,
     separator: StyleId,
+    cidr: StyleId,
+    port: StyleId,
 }
 
 impl IpV4Finder {
@@ -21,7 +23,10 @@ impl IpV4Finder {
             (?P<o2>\d{1,3})(?P<d2>\.)
             (?P<o3>\d{1,3})(?P<d3>\.)
             (?P<o4>\d{1,3})
-            (?:(?P<slash>/)(?P<mask>\d{1,2}))?
+            (?:
+                (?P<slash>/)(?P<mask>\d{1,2})
+                | (?P<colon>:)(?P<port>\d{1,5})
+            )?
             \b";
         let regex = build_regex(pattern);
 
@@ -29,6 +34,8 @@ impl IpV4Finder {
             regex,
             number: palette.intern(config.number),
             separator: palette.intern(config.separator),
+            cidr: palette.intern(config.cidr),
+            port: palette.intern(config.port),
         }
     }
 }
@@ -61,7 +68,14 @@ impl Finder for IpV4Finder {
                 if let Some(slash) = caps.name("slash") {
                     collector.push(slash.start(), slash.end(), self.separator);
                     let mask = caps.name("mask").unwrap();
-                    collector.push(mask.start(), mask.end(), self.number);
+                    collector.push(mask.start(), mask.end(), self.cidr);
+                }
+                // An out-of-range port leaves the address styled and the
+                // digits to the number finder.
+                let port = caps.name("port").filter(|p| p.as_str().parse::<u16>().is_ok());
+                if let (Some(colon), Some(port)) = (caps.name("colon"), port) {
+                    collector.push(colon.start(), colon.end(), self.separator);
+                    collector.push(port.start(), port.end(), self.port);
                 }
             }
         }
@@ -79,6 +93,8 @@ mod tests {
             IpV4Config {
                 number: Style::new().fg(Color::Blue),
                 separator: Style::new().fg(Color::Red),
+                cidr: Style::new().fg(Color::Magenta),
+                port: Style::new().fg(Color::Cyan),
             },
             &mut Palette::new(),
         )
@@ -99,6 +115,33 @@ mod tests {
         assert!(texts.contains(&"24"));
     }
 
+    #[test]
+    fn ipv4_with_port() {
+        let texts = span_texts("listening on 10.0.0.1:8080", &make_finder());
+        assert_eq!(texts, ["10", ".", "0", ".", "0", ".", "1", ":", "8080"]);
+    }
+
+    #[test]
+    fn suffixes_have_their_own_styles() {
+        let styles = |input: &str| {
+            let mut collector = Collector::new();
+            make_finder().find_spans(input, &mut collector);
+            collector.into_spans().iter().map(|span| span.style).collect::<Vec<_>>()
+        };
+
+        let cidr = styles("10.0.0.0/8");
+        let port = styles("10.0.0.1:443");
+        assert_ne!(cidr[0], cidr[8]);
+        assert_ne!(port[0], port[8]);
+        assert_ne!(cidr[8], port[8]);
+    }
+
+    #[test]
+    fn port_out_of_range_keeps_address() {
+        let texts = span_texts("10.0.0.1:99999", &make_finder());
+        assert_eq!(texts, ["10", ".", "0", ".", "0", ".", "1"]);
+    }
+
     #[test]
     fn all_zeros() {
         let texts = span_texts("0.0.0.0", &make_finder());
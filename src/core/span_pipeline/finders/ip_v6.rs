@@ -14,11 +14,22 @@ pub(crate) struct IpV6Finder {
     number: StyleId,
     letter: StyleId,
     separator: StyleId,
+    zone: StyleId,
+    cidr: StyleId,
+    port: StyleId,
 }
 
 impl IpV6Finder {
     pub fn new(config: IpV6Config, palette: &mut Palette) -> Self {
-        let pattern = r"([0-9a-fA-F:.]{3,})(?:(/)(\d{1,3}))?";
+        // A port is only unambiguous after a bracketed address: `[::1]:443`.
+        // Zone IDs may be percent-encoded (`%25eth0`) inside URLs.
+        let pattern = r"(?x)
+            (?P<open>\[)?
+            (?P<addr>[0-9a-fA-F:.]{3,})
+            (?:(?P<percent>%(?:25)?)(?P<zone>[\w.-]+))?
+            (?:(?P<close>])(?:(?P<colon>:)(?P<port>\d{1,5}))?)?
+            (?:(?P<slash>/)(?P<mask>\d{1,3}))?
+        ";
         let regex = build_regex(pattern);
 
         Self {
@@ -26,6 +37,9 @@ impl IpV6Finder {
             number: palette.intern(config.number),
             letter: palette.intern(config.letter),
             separator: palette.intern(config.separator),
+            zone: palette.intern(config.zone),
+            cidr: palette.intern(config.cidr),
+            port: palette.intern(config.port),
         }
     }
 }
@@ -37,15 +51,20 @@ impl Finder for IpV6Finder {
         }
 
         for caps in self.regex.captures_iter(input) {
-            let valid_addr = caps[1].parse::<Ipv6Addr>().is_ok();
+            let valid_addr = caps["addr"].parse::<Ipv6Addr>().is_ok();
             let valid_mask = caps
-                .get(3)
+                .name("mask")
                 .is_none_or(|m| m.as_str().parse::<u8>().is_ok_and(|v| v <= 128));
 
             if valid_addr && valid_mask {
-                let addr_match = caps.get(1).unwrap();
+                let addr_match = caps.name("addr").unwrap();
                 let addr = addr_match.as_str();
                 let offset = addr_match.start();
+                let brackets = caps.name("open").zip(caps.name("close"));
+
+                if let Some((open, _)) = brackets {
+                    collector.push(open.start(), open.end(), self.separator);
+                }
 
                 for (i, c) in addr.char_indices() {
                     let style = match c {
@@ -57,9 +76,24 @@ impl Finder for IpV6Finder {
                     collector.push(offset + i, offset + i + c.len_utf8(), style);
                 }
 
-                if let (Some(slash), Some(netmask)) = (caps.get(2), caps.get(3)) {
+                if let (Some(percent), Some(zone)) = (caps.name("percent"), caps.name("zone")) {
+                    collector.push(percent.start(), percent.end(), self.separator);
+                    collector.push(zone.start(), zone.end(), self.zone);
+                }
+
+                if let Some((_, close)) = brackets {
+                    collector.push(close.start(), close.end(), self.separator);
+
+                    let port = caps.name("port").filter(|p| p.as_str().parse::<u16>().is_ok());
+                    if let (Some(colon), Some(port)) = (caps.name("colon"), port) {
+                        collector.push(colon.start(), colon.end(), self.separator);
+                        collector.push(port.start(), port.end(), self.port);
+                    }
+                }
+
+                if let (Some(slash), Some(netmask)) = (caps.name("slash"), caps.name("mask")) {
                     collector.push(slash.start(), slash.end(), self.separator);
-                    collector.push(netmask.start(), netmask.end(), self.number);
+                    collector.push(netmask.start(), netmask.end(), self.cidr);
                 }
             }
         }
@@ -68,6 +102,7 @@ impl Finder for IpV6Finder {
 
 #[cfg(test)]
 mod tests {
+    use super::super::span_texts;
     use super::*;
     use crate::style::{Color, Style};
 
@@ -77,6 +112,9 @@ mod tests {
                 number: Style::new().fg(Color::Blue),
                 letter: Style::new().fg(Color::Yellow),
                 separator: Style::new().fg(Color::Red),
+                zone: Style::new().fg(Color::Green),
+                cidr: Style::new().fg(Color::Magenta),
+                port: Style::new().fg(Color::Cyan),
             },
             &mut Palette::new(),
         )
@@ -119,6 +157,24 @@ mod tests {
         assert!(span_count("::ffff:127.0.0.1") > 0);
     }
 
+    #[test]
+    fn zone_id() {
+        let texts = span_texts("fe80::1%eth0", &make_finder());
+        assert_eq!(texts[texts.len() - 2..], ["%", "eth0"]);
+    }
+
+    #[test]
+    fn bracketed_zone_id_with_port() {
+        let texts = span_texts("http://[fe80::1%25en0]:8080/", &make_finder());
+        assert_eq!(texts[texts.len() - 4..], ["%25", "en0", "]:", "8080"]);
+    }
+
+    #[test]
+    fn embedded_ipv4_is_part_of_the_address() {
+        let input = "from ::ffff:192.168.1.1 accepted";
+        assert_eq!(matched_range(input), Some((5, 23)));
+    }
+
     #[test]
     fn cidr_notation() {
         let input = "fe80::/10";
@@ -127,6 +183,18 @@ mod tests {
         assert_eq!(end, input.len());
     }
 
+    #[test]
+    fn bracketed_with_port() {
+        let texts = span_texts("connect [2001:db8::1]:443", &make_finder());
+        assert_eq!(texts[0], "[");
+        assert_eq!(texts[texts.len() - 2..], ["]:", "443"]);
+    }
+
+    #[test]
+    fn unbalanced_bracket_styles_only_the_address() {
+        assert_eq!(matched_range("[::1 and more"), Some((1, 4)));
+    }
+
     #[test]
     fn mask_over_128_no_match() {
         assert_eq!(span_count("fe80::/129"), 0);
@@ -8,26 +8,58 @@ use super::super::span::{Collector, Finder};
 /// Matches all configured keywords with a single automaton; each pattern
 /// carries the style of the config it came from, plus whether that style
 /// has a background and therefore renders as a padded badge.
+///
+/// The automaton ignores ASCII case as soon as one config asks for it; the
+/// patterns of the other configs then check the matched text verbatim.
 #[derive(Debug, Clone)]
 pub(crate) struct KeywordFinder {
     ac: AhoCorasick,
-    styles: Vec<(StyleId, bool)>,
+    patterns: Vec<Pattern>,
+}
+
+#[derive(Debug, Clone)]
+struct Pattern {
+    style: StyleId,
+    padded: bool,
+    whole_word: bool,
+    /// The word, when the automaton ignores case but this pattern must not.
+    verbatim: Option<Box<[u8]>>,
 }
 
 impl KeywordFinder {
     pub fn new(configs: &[KeywordConfig], palette: &mut Palette) -> Result<Self, BuildError> {
+        let ignore_case = configs.iter().any(|config| config.case_insensitive);
         let words = configs.iter().flat_map(|config| &config.words);
-        let styles = configs
+        let patterns = configs
             .iter()
             .flat_map(|config| {
-                let style = (palette.intern(config.style), config.style.bg.is_some());
-                config.words.iter().map(move |_| style)
+                let style = palette.intern(config.style);
+                config.words.iter().map(move |word| Pattern {
+                    style,
+                    padded: config.style.bg.is_some(),
+                    whole_word: config.whole_word,
+                    verbatim: (ignore_case && !config.case_insensitive).then(|| word.as_bytes().into()),
+                })
             })
             .collect();
 
-        let ac = AhoCorasickBuilder::new().match_kind(MatchKind::Standard).build(words)?;
+        let ac = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::Standard)
+            .ascii_case_insensitive(ignore_case)
+            .build(words)?;
 
-        Ok(Self { ac, styles })
+        Ok(Self { ac, patterns })
+    }
+
+    fn accepts(&self, hay: &[u8], m: &Match) -> bool {
+        let pattern = &self.patterns[m.pattern().as_usize()];
+        if pattern.whole_word && !is_word_boundary(hay, m.start(), m.end()) {
+            return false;
+        }
+        pattern
+            .verbatim
+            .as_deref()
+            .is_none_or(|word| &hay[m.start()..m.end()] == word)
     }
 }
 
@@ -48,7 +80,7 @@ impl Finder for KeywordFinder {
         let mut matches: Vec<Match> = self
             .ac
             .find_overlapping_iter(bytes)
-            .filter(|m| is_word_boundary(bytes, m.start(), m.end()))
+            .filter(|m| self.accepts(bytes, m))
             .collect();
         matches.sort_by(|a, b| a.start().cmp(&b.start()).then(b.end().cmp(&a.end())));
 
@@ -59,11 +91,11 @@ impl Finder for KeywordFinder {
             }
             next_start = m.end();
 
-            let (style, padded) = self.styles[m.pattern().as_usize()];
-            if padded {
-                collector.push_padded(m.start(), m.end(), style);
+            let pattern = &self.patterns[m.pattern().as_usize()];
+            if pattern.padded {
+                collector.push_padded(m.start(), m.end(), pattern.style);
             } else {
-                collector.push(m.start(), m.end(), style);
+                collector.push(m.start(), m.end(), pattern.style);
             }
         }
     }
@@ -78,6 +110,7 @@ mod tests {
         KeywordConfig {
             words: words.iter().map(ToString::to_string).collect(),
             style,
+            ..KeywordConfig::default()
         }
     }
 
@@ -140,6 +173,39 @@ mod tests {
         assert_eq!(texts, ["lost"]);
     }
 
+    #[test]
+    fn case_insensitive_matches_any_case() {
+        let finder = finder(&[KeywordConfig {
+            case_insensitive: true,
+            ..kw(&["error"], Style::new().fg(Color::Red))
+        }]);
+        let texts = super::super::span_texts("Error, error and ERROR", &finder);
+        assert_eq!(texts, ["Error", "error", "ERROR"]);
+    }
+
+    #[test]
+    fn case_sensitive_configs_stay_exact_next_to_insensitive_ones() {
+        let finder = finder(&[
+            kw(&["INFO"], Style::new().fg(Color::White)),
+            KeywordConfig {
+                case_insensitive: true,
+                ..kw(&["error"], Style::new().fg(Color::Red))
+            },
+        ]);
+        let texts = super::super::span_texts("info INFO Error", &finder);
+        assert_eq!(texts, ["INFO", "Error"]);
+    }
+
+    #[test]
+    fn whole_word_off_matches_inside_identifiers() {
+        let finder = finder(&[KeywordConfig {
+            whole_word: false,
+            ..kw(&["OutOfMemory"], Style::new().fg(Color::Red))
+        }]);
+        let texts = super::super::span_texts("java.lang.OutOfMemoryError", &finder);
+        assert_eq!(texts, ["OutOfMemory"]);
+    }
+
     #[test]
     fn each_keyword_keeps_the_style_of_its_config() {
         let red = Style::new().fg(Color::Red);
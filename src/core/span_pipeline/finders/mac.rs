@@ -0,0 +1,136 @@
+use super::build_regex;
+use memchr::memchr3;
+use regex::Regex;
+
+use crate::core::config::MacConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Styles MAC addresses in colon (`3c:22:fb:12:ab:cd`), hyphen
+/// (`3C-22-FB-12-AB-CD`) and dotted Cisco (`3c22.fb12.abcd`) notation.
+#[derive(Debug, Clone)]
+pub(crate) struct MacFinder {
+    regex: Regex,
+    number: StyleId,
+    letter: StyleId,
+    separator: StyleId,
+}
+
+impl MacFinder {
+    pub fn new(config: MacConfig, palette: &mut Palette) -> Self {
+        let pattern = r"(?x)\b
+            (?:
+                (?:[0-9a-fA-F]{2}:){5}[0-9a-fA-F]{2}
+                | (?:[0-9a-fA-F]{2}-){5}[0-9a-fA-F]{2}
+                | (?:[0-9a-fA-F]{4}\.){2}[0-9a-fA-F]{4}
+            )
+            \b";
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            number: palette.intern(config.number),
+            letter: palette.intern(config.letter),
+            separator: palette.intern(config.separator),
+        }
+    }
+}
+
+/// True when `sep` at `i` joins the match to another hex group, as in the
+/// longer runs of an IPv6 address or an EUI-64.
+fn continues_hex_run(bytes: &[u8], sep: usize, hex: usize) -> bool {
+    matches!(bytes.get(sep), Some(b':' | b'-' | b'.')) && bytes.get(hex).is_some_and(u8::is_ascii_hexdigit)
+}
+
+impl Finder for MacFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+        if memchr3(b':', b'-', b'.', bytes).is_none() {
+            return;
+        }
+
+        for m in self.regex.find_iter(input) {
+            let before = m
+                .start()
+                .checked_sub(1)
+                .is_some_and(|sep| sep.checked_sub(1).is_some_and(|hex| continues_hex_run(bytes, sep, hex)));
+            if before || continues_hex_run(bytes, m.end(), m.end() + 1) {
+                continue;
+            }
+
+            for (i, c) in m.as_str().char_indices() {
+                let style = match c {
+                    '0'..='9' => self.number,
+                    'a'..='f' | 'A'..='F' => self.letter,
+                    _ => self.separator,
+                };
+                collector.push(m.start() + i, m.start() + i + 1, style);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> MacFinder {
+        MacFinder::new(
+            MacConfig {
+                number: Style::new().fg(Color::Blue),
+                letter: Style::new().fg(Color::Yellow),
+                separator: Style::new().fg(Color::Red),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn colon_notation() {
+        let texts = span_texts("lease for 3c:22:fb:12:ab:cd", &make_finder());
+        assert_eq!(texts, ["3", "c", ":", "22", ":", "fb", ":", "12", ":", "ab", ":", "cd"]);
+    }
+
+    #[test]
+    fn hyphen_notation() {
+        assert_eq!(
+            span_texts("3C-22-FB-12-AB-CD", &make_finder()).concat(),
+            "3C-22-FB-12-AB-CD"
+        );
+    }
+
+    #[test]
+    fn cisco_dotted_notation() {
+        assert_eq!(
+            span_texts("3c22.fb12.abcd up", &make_finder()).concat(),
+            "3c22.fb12.abcd"
+        );
+    }
+
+    #[test]
+    fn key_value_prefix_matches() {
+        assert_eq!(
+            span_texts("hwaddr=3c:22:fb:12:ab:cd,", &make_finder()).concat(),
+            "3c:22:fb:12:ab:cd"
+        );
+    }
+
+    #[test]
+    fn longer_hex_runs_no_match() {
+        assert!(span_texts("eui 3c:22:fb:ff:fe:12:ab:cd", &make_finder()).is_empty());
+        assert!(span_texts("fe80:3c:22:fb:12:ab:cd", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn mixed_separators_no_match() {
+        assert!(span_texts("3c:22-fb:12-ab:cd", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn times_no_match() {
+        assert!(span_texts("12:30:45.123", &make_finder()).is_empty());
+    }
+}
@@ -8,24 +8,39 @@
 
 use ::regex::{Regex, RegexBuilder};
 
+use super::palette::{Palette, StyleId};
+use crate::style::Style;
+
+pub(crate) mod container;
+pub(crate) mod date_compact;
 pub(crate) mod date_dash;
+pub(crate) mod date_month;
 pub(crate) mod date_time;
+pub(crate) mod delta;
 pub(crate) mod duration;
 pub(crate) mod email;
+pub(crate) mod epoch;
+pub(crate) mod hash;
+pub(crate) mod http_status;
 pub(crate) mod ip_v4;
 pub(crate) mod ip_v6;
 pub(crate) mod json;
 pub(crate) mod jvm_stack;
 pub(crate) mod key_value;
 pub(crate) mod keyword;
+pub(crate) mod mac;
 pub(crate) mod number;
 pub(crate) mod pointer;
 pub(crate) mod quote;
 pub(crate) mod regex;
+pub(crate) mod size;
+pub(crate) mod source_location;
+pub(crate) mod syslog;
 pub(crate) mod unix_path;
 pub(crate) mod unix_process;
 pub(crate) mod url;
 pub(crate) mod uuid;
+pub(crate) mod windows_path;
 
 /// Hardcoded finder regexes are byte-mode: `\w`/`\d`/`\s`/`\b` stay ASCII and
 /// skip the Unicode tables in the hot path.
@@ -36,6 +51,42 @@ pub(crate) fn build_regex(pattern: &str) -> Regex {
         .expect("hardcoded finder regex must compile")
 }
 
+/// `warn` and `error` limits for the finders that style a value by its
+/// magnitude. A value at or above a limit is styled with that limit's style.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Thresholds<T> {
+    warn: Option<T>,
+    error: Option<T>,
+    warn_style: StyleId,
+    error_style: StyleId,
+}
+
+impl<T: PartialOrd> Thresholds<T> {
+    pub(crate) fn new(
+        (warn, error): (Option<T>, Option<T>),
+        (warn_style, error_style): (Style, Style),
+        palette: &mut Palette,
+    ) -> Self {
+        Self {
+            warn,
+            error,
+            warn_style: palette.intern(warn_style),
+            error_style: palette.intern(error_style),
+        }
+    }
+
+    /// The style that replaces the usual ones once `value` reaches a limit.
+    pub(crate) fn style_for(&self, value: &T) -> Option<StyleId> {
+        if self.error.as_ref().is_some_and(|error| value >= error) {
+            Some(self.error_style)
+        } else if self.warn.as_ref().is_some_and(|warn| value >= warn) {
+            Some(self.warn_style)
+        } else {
+            None
+        }
+    }
+}
+
 /// The texts of all spans a finder produces for `input`.
 #[cfg(test)]
 pub(crate) fn span_texts<'a>(input: &'a str, finder: &impl super::span::Finder) -> Vec<&'a str> {
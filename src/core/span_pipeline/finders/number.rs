@@ -1,4 +1,4 @@
-use super::build_regex;
+use super::{Thresholds, build_regex};
 use regex::Regex;
 
 use crate::core::config::NumberConfig;
@@ -10,6 +10,7 @@ use super::super::span::{Collector, Finder};
 pub(crate) struct NumberFinder {
     regex: Regex,
     style: StyleId,
+    thresholds: Thresholds<f64>,
 }
 
 impl NumberFinder {
@@ -26,6 +27,11 @@ impl NumberFinder {
         Self {
             regex,
             style: palette.intern(config.style),
+            thresholds: Thresholds::new(
+                (config.warn, config.error),
+                (config.warn_style, config.error_style),
+                palette,
+            ),
         }
     }
 }
@@ -37,7 +43,13 @@ impl Finder for NumberFinder {
         }
 
         for m in self.regex.find_iter(input) {
-            collector.push(m.start(), m.end(), self.style);
+            let style = m
+                .as_str()
+                .parse::<f64>()
+                .ok()
+                .and_then(|value| self.thresholds.style_for(&value))
+                .unwrap_or(self.style);
+            collector.push(m.start(), m.end(), style);
         }
     }
 }
@@ -52,6 +64,7 @@ mod tests {
         NumberFinder::new(
             NumberConfig {
                 style: Style::new().fg(Color::Cyan),
+                ..NumberConfig::default()
             },
             &mut Palette::new(),
         )
@@ -63,6 +76,28 @@ mod tests {
         assert_eq!(texts, ["42", "3.14"]);
     }
 
+    #[test]
+    fn numbers_past_a_threshold_change_style() {
+        let mut palette = Palette::new();
+        let finder = NumberFinder::new(
+            NumberConfig {
+                style: Style::new().fg(Color::Cyan),
+                warn_style: Style::new().fg(Color::Yellow),
+                error_style: Style::new().fg(Color::Red),
+                warn: Some(100.0),
+                error: Some(1000.0),
+            },
+            &mut palette,
+        );
+        let expected =
+            [Color::Cyan, Color::Yellow, Color::Yellow, Color::Red].map(|c| palette.intern(Style::new().fg(c)));
+
+        let mut collector = Collector::new();
+        finder.find_spans("queue 99 then 100 then 999.5 then 1000", &mut collector);
+        let styles: Vec<StyleId> = collector.into_spans().iter().map(|span| span.style).collect();
+        assert_eq!(styles, expected);
+    }
+
     #[test]
     fn no_match_produces_no_spans() {
         assert!(span_texts("no numbers here", &make_finder()).is_empty());
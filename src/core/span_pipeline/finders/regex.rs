@@ -1,27 +1,54 @@
-use regex::{Error, Regex};
+use regex::Regex;
 
+use crate::core::config::RegexConfig;
+use crate::core::highlighter::Error;
 use crate::style::Style;
 
 use super::super::palette::{Palette, StyleId};
 use super::super::span::{Collector, Finder};
 
-/// With exactly one capture group, only the captured portion is styled
-/// (falling back to the full match when the group doesn't participate);
-/// otherwise the full match is styled.
+/// With `groups`, each named group that participates gets its own style, a
+/// nested group's winning over its enclosing one's, and the rest of the match
+/// gets `style` unless that is left unset. Otherwise, with exactly one capture
+/// group, only the captured portion is styled (falling back to the full match
+/// when the group doesn't participate); otherwise the full match is styled.
 #[derive(Debug, Clone)]
 pub(crate) struct RegexFinder {
     regex: Regex,
-    style: StyleId,
+    style: Option<StyleId>,
+    groups: Vec<(usize, StyleId)>,
     single_capture_group: bool,
 }
 
 impl RegexFinder {
-    pub fn new(pattern: &str, style: Style, palette: &mut Palette) -> Result<Self, Error> {
-        let regex = Regex::new(pattern)?;
+    pub fn new(config: &RegexConfig, palette: &mut Palette) -> Result<Self, Error> {
+        let regex = Regex::new(&config.regex)?;
+
+        let mut groups = Vec::with_capacity(config.groups.len());
+        for (name, style) in &config.groups {
+            let index = regex
+                .capture_names()
+                .position(|group| group == Some(name.as_str()))
+                .ok_or_else(|| Error::UnknownGroup {
+                    regex: config.regex.clone(),
+                    group: name.clone(),
+                })?;
+            groups.push((index, palette.intern(*style)));
+        }
+        // Inner groups come later in the pattern; pushed first, they win.
+        groups.sort_unstable_by_key(|&(index, _)| std::cmp::Reverse(index));
+
+        let style = if groups.is_empty() || config.style != Style::default() {
+            Some(palette.intern(config.style))
+        } else {
+            None
+        };
+
         Ok(Self {
             single_capture_group: regex.captures_len() == 2,
             regex,
-            style: palette.intern(style),
+            style,
+            groups,
         })
     }
 }
@@ -30,12 +57,24 @@ impl Finder for RegexFinder {
     fn find_spans(&self, input: &str, collector: &mut Collector) {
         for caps in self.regex.captures_iter(input) {
             let entire_match = caps.get(0).unwrap();
+            if !self.groups.is_empty() {
+                for &(index, style) in &self.groups {
+                    if let Some(group) = caps.get(index) {
+                        collector.push(group.start(), group.end(), style);
+                    }
+                }
+                if let Some(style) = self.style {
+                    collector.push(entire_match.start(), entire_match.end(), style);
+                }
+                continue;
+            }
+
             let m = if self.single_capture_group {
                 caps.get(1).unwrap_or(entire_match)
             } else {
                 entire_match
             };
-            collector.push(m.start(), m.end(), self.style);
+            collector.push(m.start(), m.end(), self.style.unwrap());
         }
     }
 }
@@ -46,10 +85,23 @@ mod tests {
     use crate::style::Color;
 
     fn span_texts<'a>(input: &'a str, pattern: &str) -> Vec<&'a str> {
-        let finder = RegexFinder::new(pattern, Style::new().fg(Color::Red), &mut Palette::new()).unwrap();
+        let config = RegexConfig {
+            regex: pattern.to_string(),
+            style: Style::new().fg(Color::Red),
+            ..RegexConfig::default()
+        };
+        let finder = RegexFinder::new(&config, &mut Palette::new()).unwrap();
         super::super::span_texts(input, &finder)
     }
 
+    fn grouped(pattern: &str, style: Style, groups: &[(&str, Style)]) -> RegexConfig {
+        RegexConfig {
+            regex: pattern.to_string(),
+            style,
+            groups: groups.iter().map(|&(name, style)| (name.to_string(), style)).collect(),
+        }
+    }
+
     #[test]
     fn simple_pattern() {
         let texts = span_texts("hello world", "world");
@@ -81,4 +133,48 @@ mod tests {
         let texts = span_texts("abc 123 def 456", r"\d+");
         assert_eq!(texts, ["123", "456"]);
     }
+
+    #[test]
+    fn named_groups_get_their_own_styles() {
+        let red = Style::new().fg(Color::Red);
+        let blue = Style::new().fg(Color::Blue);
+        let mut palette = Palette::new();
+        let finder = RegexFinder::new(
+            &grouped(
+                r"(?P<svc>\w+)/(?P<ver>v\d+)",
+                Style::default(),
+                &[("svc", red), ("ver", blue)],
+            ),
+            &mut palette,
+        )
+        .unwrap();
+
+        let mut collector = Collector::new();
+        finder.find_spans("deploy api/v2 now", &mut collector);
+        let spans: Vec<_> = collector
+            .into_spans()
+            .iter()
+            .map(|s| (&"deploy api/v2 now"[s.start..s.end], s.style))
+            .collect();
+
+        assert_eq!(spans, [("v2", palette.intern(blue)), ("api", palette.intern(red))]);
+    }
+
+    #[test]
+    fn style_covers_the_rest_of_a_grouped_match() {
+        let config = grouped(
+            r"(?P<svc>\w+)/(?P<ver>v\d+)",
+            Style::new().faint(),
+            &[("ver", Style::new().bold())],
+        );
+        let finder = RegexFinder::new(&config, &mut Palette::new()).unwrap();
+        assert_eq!(super::super::span_texts("api/v2", &finder), ["v2", "api/v2"]);
+    }
+
+    #[test]
+    fn unknown_group_is_an_error() {
+        let config = grouped(r"(?P<svc>\w+)", Style::default(), &[("version", Style::new().bold())]);
+        let error = RegexFinder::new(&config, &mut Palette::new()).unwrap_err();
+        assert!(matches!(error, Error::UnknownGroup { group, .. } if group == "version"));
+    }
 }
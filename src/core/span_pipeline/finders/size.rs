@@ -0,0 +1,169 @@
+use super::{Thresholds, build_regex};
+use memchr::memchr3;
+use regex::Regex;
+
+use crate::core::config::{ByteSize, SizeConfig};
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Styles byte sizes (`512MiB`, `1.2 GB`, `4096 bytes`) and percentages
+/// (`87%`). A size or percentage at or above a configured threshold is styled
+/// as a whole with the `warn` or `error` style instead.
+#[derive(Debug, Clone)]
+pub(crate) struct SizeFinder {
+    regex: Regex,
+    value: StyleId,
+    separator: StyleId,
+    unit: StyleId,
+    sizes: Thresholds<ByteSize>,
+    percentages: Thresholds<f64>,
+}
+
+impl SizeFinder {
+    pub fn new(config: SizeConfig, palette: &mut Palette) -> Self {
+        let pattern = r"(?x)
+            \b
+            (?P<integer>\d+)                    # integer part
+            (?:(?P<dot>\.)(?P<fraction>\d+))?   # optional fractional part
+            (?:
+                \x20?(?P<size_unit>[KMGTP]iB|[kKMGTP]B|B|bytes)\b
+                | (?P<percent>%)
+            )
+        ";
+        let regex = build_regex(pattern);
+
+        Self {
+            regex,
+            value: palette.intern(config.value),
+            separator: palette.intern(config.separator),
+            unit: palette.intern(config.unit),
+            sizes: Thresholds::new(
+                (config.size_warn, config.size_error),
+                (config.warn_style, config.error_style),
+                palette,
+            ),
+            percentages: Thresholds::new(
+                (config.percent_warn, config.percent_error),
+                (config.warn_style, config.error_style),
+                palette,
+            ),
+        }
+    }
+}
+
+impl Finder for SizeFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        // Every match ends in `%`, a unit with a `B`, or `bytes`.
+        if memchr3(b'%', b'B', b'b', input.as_bytes()).is_none() {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            let whole = caps.get(0).unwrap();
+            let integer = caps.name("integer").unwrap();
+            let number_end = caps.name("fraction").map_or(integer.end(), |fraction| fraction.end());
+            let Ok(value) = input[integer.start()..number_end].parse::<f64>() else {
+                continue;
+            };
+
+            let exceeded = match caps.name("size_unit") {
+                Some(unit) => ByteSize::from_value(value, unit.as_str()).and_then(|size| self.sizes.style_for(&size)),
+                None => self.percentages.style_for(&value),
+            };
+
+            if let Some(style) = exceeded {
+                collector.push(whole.start(), whole.end(), style);
+                continue;
+            }
+
+            collector.push(integer.start(), integer.end(), self.value);
+            if let Some(dot) = caps.name("dot") {
+                let fraction = caps.name("fraction").unwrap();
+                collector.push(dot.start(), dot.end(), self.separator);
+                collector.push(fraction.start(), fraction.end(), self.value);
+            }
+
+            let unit = caps.name("size_unit").or_else(|| caps.name("percent")).unwrap();
+            collector.push(unit.start(), unit.end(), self.unit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn config() -> SizeConfig {
+        SizeConfig {
+            value: Style::new().fg(Color::Blue),
+            separator: Style::new().fg(Color::Magenta),
+            unit: Style::new().fg(Color::Magenta).italic(),
+            warn_style: Style::new().fg(Color::Yellow),
+            error_style: Style::new().fg(Color::Red),
+            size_warn: None,
+            size_error: None,
+            percent_warn: None,
+            percent_error: None,
+        }
+    }
+
+    fn styles(finder: &SizeFinder, input: &str) -> Vec<StyleId> {
+        let mut collector = Collector::new();
+        finder.find_spans(input, &mut collector);
+
+        collector.into_spans().iter().map(|span| span.style).collect()
+    }
+
+    #[test]
+    fn finds_sizes_and_percentages() {
+        let finder = SizeFinder::new(config(), &mut Palette::new());
+        let texts = span_texts("heap 512MiB of 1.2 GB, disk 87%", &finder);
+        assert_eq!(texts, ["512", "MiB", "1", ".", "2", "GB", "87", "%"]);
+    }
+
+    #[test]
+    fn finds_plain_bytes() {
+        let finder = SizeFinder::new(config(), &mut Palette::new());
+        assert_eq!(span_texts("wrote 4096 bytes", &finder), ["4096", "bytes"]);
+    }
+
+    #[test]
+    fn thresholds_style_the_whole_token() {
+        let mut palette = Palette::new();
+        let finder = SizeFinder::new(
+            SizeConfig {
+                size_warn: Some(ByteSize(1024 * 1024 * 1024)),
+                size_error: Some(ByteSize(4 * 1024 * 1024 * 1024)),
+                percent_warn: Some(80.0),
+                percent_error: Some(95.0),
+                ..config()
+            },
+            &mut palette,
+        );
+        let warn = palette.intern(Style::new().fg(Color::Yellow));
+        let error = palette.intern(Style::new().fg(Color::Red));
+
+        assert_eq!(span_texts("heap 3.8GiB", &finder), ["3.8GiB"]);
+        assert_eq!(styles(&finder, "heap 3.8GiB"), [warn]);
+        assert_eq!(styles(&finder, "heap 4GiB"), [error]);
+        assert_eq!(styles(&finder, "disk 95%"), [error]);
+        assert_eq!(styles(&finder, "disk 81.5%"), [warn]);
+        assert_eq!(styles(&finder, "disk 12%").len(), 2);
+        assert_eq!(styles(&finder, "heap 512MiB").len(), 2);
+    }
+
+    #[test]
+    fn decimal_and_binary_units_differ() {
+        assert!(ByteSize::from_value(1.0, "GB").unwrap() < ByteSize::from_value(1.0, "GiB").unwrap());
+    }
+
+    #[test]
+    fn units_must_terminate_the_word() {
+        let finder = SizeFinder::new(config(), &mut Palette::new());
+        assert!(span_texts("5Bytes 3MBps v1B", &finder).is_empty());
+        assert!(span_texts("version 2", &finder).is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+use super::build_regex;
+use memchr::memchr;
+use regex::Regex;
+
+use crate::core::config::SourceLocationConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Extensions a path must end in to count as a source location. Requiring one
+/// keeps bare words, hostnames and versions like `v1.2` from matching.
+const EXTENSIONS: &str = "c|cc|cpp|cs|css|cxx|dart|ex|exs|erl|fs|go|gradle|h|hpp|hs|html|java|js|json|jsx|kt|kts|lua|m|md|ml|mjs|php|pl|proto|py|rb|rs|scala|scss|sh|sql|svelte|swift|tf|toml|ts|tsx|vue|xml|yaml|yml|zig";
+
+/// Styles compiler-style source locations: relative or absolute paths with a
+/// known extension, optionally followed by `:line` or `:line:col`
+/// (`src/io/routing.rs:42:7`, `main.go:88`, `./app/views.py`).
+#[derive(Debug, Clone)]
+pub(crate) struct SourceLocationFinder {
+    regex: Regex,
+    segment: StyleId,
+    separator: StyleId,
+    line: StyleId,
+    column: StyleId,
+}
+
+impl SourceLocationFinder {
+    pub fn new(config: SourceLocationConfig, palette: &mut Palette) -> Self {
+        let pattern = format!(
+            r#"(?x)
+            (?:^|[\s"'(\[=,])
+            (?P<path>
+                (?:/|~/|(?:\.\.?/)+)?
+                (?:[\w.-]+/)*
+                [\w-][\w.-]*\.(?:{EXTENSIONS})
+            )
+            (?:
+                (?P<line_sep>:)(?P<line>\d+)
+                (?:(?P<column_sep>:)(?P<column>\d+))?
+            )?
+            \b
+            "#
+        );
+        let regex = build_regex(&pattern);
+
+        Self {
+            regex,
+            segment: palette.intern(config.segment),
+            separator: palette.intern(config.separator),
+            line: palette.intern(config.line),
+            column: palette.intern(config.column),
+        }
+    }
+
+    fn push_path(&self, bytes: &[u8], offset: usize, collector: &mut Collector) {
+        let mut seg_start = None;
+
+        for (i, &b) in bytes.iter().enumerate() {
+            if b == b'/' {
+                if let Some(start) = seg_start.take() {
+                    collector.push(offset + start, offset + i, self.segment);
+                }
+                collector.push(offset + i, offset + i + 1, self.separator);
+            } else if seg_start.is_none() {
+                seg_start = Some(i);
+            }
+        }
+
+        if let Some(start) = seg_start {
+            collector.push(offset + start, offset + bytes.len(), self.segment);
+        }
+    }
+}
+
+impl Finder for SourceLocationFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if memchr(b'.', input.as_bytes()).is_none() {
+            return;
+        }
+
+        for caps in self.regex.captures_iter(input) {
+            let path = caps.name("path").unwrap();
+            self.push_path(path.as_str().as_bytes(), path.start(), collector);
+
+            if let (Some(sep), Some(line)) = (caps.name("line_sep"), caps.name("line")) {
+                collector.push(sep.start(), sep.end(), self.separator);
+                collector.push(line.start(), line.end(), self.line);
+            }
+            if let (Some(sep), Some(column)) = (caps.name("column_sep"), caps.name("column")) {
+                collector.push(sep.start(), sep.end(), self.separator);
+                collector.push(column.start(), column.end(), self.column);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> SourceLocationFinder {
+        SourceLocationFinder::new(
+            SourceLocationConfig {
+                segment: Style::new().fg(Color::Green),
+                separator: Style::new().fg(Color::Yellow),
+                line: Style::new().fg(Color::Cyan),
+                column: Style::new().fg(Color::Magenta),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn relative_path_with_line_and_column() {
+        let texts = span_texts("error at src/io/routing.rs:42:7", &make_finder());
+        assert_eq!(texts, ["src", "/", "io", "/", "routing.rs", ":", "42", ":", "7"]);
+    }
+
+    #[test]
+    fn bare_file_with_line() {
+        assert_eq!(
+            span_texts("main.go:88: undefined: foo", &make_finder()),
+            ["main.go", ":", "88"]
+        );
+    }
+
+    #[test]
+    fn path_without_location() {
+        assert_eq!(
+            span_texts("reading ./app/views.py", &make_finder()),
+            [".", "/", "app", "/", "views.py"]
+        );
+    }
+
+    #[test]
+    fn quoted_python_traceback_path() {
+        let texts = span_texts(r#"File "app/main.py", line 42"#, &make_finder());
+        assert_eq!(texts, ["app", "/", "main.py"]);
+    }
+
+    #[test]
+    fn line_and_column_styles_differ() {
+        let mut collector = Collector::new();
+        make_finder().find_spans("lib.rs:1:2", &mut collector);
+        let spans = collector.into_spans();
+
+        assert_ne!(spans[2].style, spans[4].style);
+    }
+
+    #[test]
+    fn unknown_extensions_and_hosts_no_match() {
+        assert!(span_texts("connected to example.com:443", &make_finder()).is_empty());
+        assert!(span_texts("upgraded to v1.2.3", &make_finder()).is_empty());
+        assert!(span_texts("archive.tar.gz", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn extension_must_end_the_name() {
+        assert!(span_texts("see main.rsx for details", &make_finder()).is_empty());
+        assert!(span_texts("https://example.com/app.js", &make_finder()).is_empty());
+    }
+}
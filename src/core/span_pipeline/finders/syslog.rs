@@ -0,0 +1,327 @@
+use super::build_regex;
+use memchr::memchr;
+use regex::{Captures, Regex};
+
+use crate::core::config::SyslogConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// Styles the header of RFC 3164 and RFC 5424 syslog lines. Timestamps are
+/// left to the date finders; only the fields around them are claimed here.
+#[derive(Debug, Clone)]
+pub(crate) struct SyslogFinder {
+    rfc5424: Regex,
+    rfc3164: Regex,
+    priority: StyleId,
+    version: StyleId,
+    hostname: StyleId,
+    app: StyleId,
+    pid: StyleId,
+    msgid: StyleId,
+    sd_id: StyleId,
+    sd_param_key: StyleId,
+    sd_param_value: StyleId,
+    symbol: StyleId,
+}
+
+impl SyslogFinder {
+    pub fn new(config: SyslogConfig, palette: &mut Palette) -> Self {
+        // Header fields are printable ASCII per RFC 5424; `-` is the NILVALUE.
+        // The structured data that follows is parsed by hand.
+        let rfc5424_pattern = r"(?xm)
+            ^
+            (?: (?P<pri_open><) (?P<pri>\d{1,3}) (?P<pri_close>>) )?
+            (?P<version>[1-9]\d?) \x20
+            (?: - | \d{4}-\d{2}-\d{2}T[!-~]+ ) \x20
+            (?P<host>[!-~]+) \x20
+            (?P<app>[!-~]+) \x20
+            (?P<pid>[!-~]+) \x20
+            (?P<msgid>[!-~]+) \x20
+            (?P<sd>-|\[)
+        ";
+        let rfc5424 = build_regex(rfc5424_pattern);
+
+        // BSD syslog, optionally with the ISO timestamps rsyslog writes.
+        let rfc3164_pattern = r"(?xm)
+            ^
+            (?: (?P<pri_open><) (?P<pri>\d{1,3}) (?P<pri_close>>) )?
+            (?: [A-Z][a-z]{2}\x20[\x20\d]\d\x20\d{2}:\d{2}:\d{2} | \d{4}-\d{2}-\d{2}T[!-~]+ ) \x20
+            (?P<host>[\w.:-]+) \x20
+            (?P<app>[\w./-]+)
+            (?: (?P<pid_open>\[) (?P<pid>\d+) (?P<pid_close>]) )?
+            (?P<colon>:)
+        ";
+        let rfc3164 = build_regex(rfc3164_pattern);
+
+        Self {
+            rfc5424,
+            rfc3164,
+            priority: palette.intern(config.priority),
+            version: palette.intern(config.version),
+            hostname: palette.intern(config.hostname),
+            app: palette.intern(config.app),
+            pid: palette.intern(config.pid),
+            msgid: palette.intern(config.msgid),
+            sd_id: palette.intern(config.sd_id),
+            sd_param_key: palette.intern(config.sd_param_key),
+            sd_param_value: palette.intern(config.sd_param_value),
+            symbol: palette.intern(config.symbol),
+        }
+    }
+
+    fn push_priority(&self, caps: &Captures, collector: &mut Collector) {
+        if let (Some(open), Some(pri), Some(close)) = (caps.name("pri_open"), caps.name("pri"), caps.name("pri_close"))
+        {
+            collector.push(open.start(), open.end(), self.symbol);
+            collector.push(pri.start(), pri.end(), self.priority);
+            collector.push(close.start(), close.end(), self.symbol);
+        }
+    }
+
+    /// Pushes a header field unless it is the NILVALUE `-`.
+    fn push_field(caps: &Captures, name: &str, style: StyleId, collector: &mut Collector) {
+        let field = caps.name(name).unwrap();
+        if field.as_str() != "-" {
+            collector.push(field.start(), field.end(), style);
+        }
+    }
+
+    fn find_rfc5424(&self, input: &str, collector: &mut Collector) -> bool {
+        let mut found = false;
+
+        for caps in self.rfc5424.captures_iter(input) {
+            let sd = caps.name("sd").unwrap();
+            let elements = if sd.as_str() == "[" {
+                match parse_structured_data(input.as_bytes(), sd.start()) {
+                    Some(elements) => elements,
+                    None => continue,
+                }
+            } else {
+                Vec::new()
+            };
+            found = true;
+
+            self.push_priority(&caps, collector);
+            let version = caps.name("version").unwrap();
+            collector.push(version.start(), version.end(), self.version);
+            Self::push_field(&caps, "host", self.hostname, collector);
+            Self::push_field(&caps, "app", self.app, collector);
+            Self::push_field(&caps, "pid", self.pid, collector);
+            Self::push_field(&caps, "msgid", self.msgid, collector);
+
+            for (start, end, part) in elements {
+                let style = match part {
+                    SdPart::Symbol => self.symbol,
+                    SdPart::Id => self.sd_id,
+                    SdPart::Key => self.sd_param_key,
+                    SdPart::Value => self.sd_param_value,
+                };
+                collector.push(start, end, style);
+            }
+        }
+
+        found
+    }
+
+    fn find_rfc3164(&self, input: &str, collector: &mut Collector) {
+        for caps in self.rfc3164.captures_iter(input) {
+            self.push_priority(&caps, collector);
+            Self::push_field(&caps, "host", self.hostname, collector);
+            Self::push_field(&caps, "app", self.app, collector);
+
+            if let (Some(open), Some(pid), Some(close)) =
+                (caps.name("pid_open"), caps.name("pid"), caps.name("pid_close"))
+            {
+                collector.push(open.start(), open.end(), self.symbol);
+                collector.push(pid.start(), pid.end(), self.pid);
+                collector.push(close.start(), close.end(), self.symbol);
+            }
+
+            let colon = caps.name("colon").unwrap();
+            collector.push(colon.start(), colon.end(), self.symbol);
+        }
+    }
+}
+
+impl Finder for SyslogFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        if memchr(b' ', input.as_bytes()).is_none() {
+            return;
+        }
+
+        if !self.find_rfc5424(input, collector) {
+            self.find_rfc3164(input, collector);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SdPart {
+    Symbol,
+    Id,
+    Key,
+    Value,
+}
+
+/// Parses one or more `[id key="value" ...]` elements starting at `start`.
+/// Returns `None` for anything malformed, so a half-parsed line never
+/// gets partially styled.
+fn parse_structured_data(bytes: &[u8], start: usize) -> Option<Vec<(usize, usize, SdPart)>> {
+    let is_name_byte = |b: u8| b.is_ascii_graphic() && !matches!(b, b'=' | b']' | b'"');
+    let mut parts = Vec::new();
+    let mut pos = start;
+
+    while bytes.get(pos) == Some(&b'[') {
+        parts.push((pos, pos + 1, SdPart::Symbol));
+        pos += 1;
+
+        let id_start = pos;
+        while bytes.get(pos).is_some_and(|&b| is_name_byte(b)) {
+            pos += 1;
+        }
+        if pos == id_start {
+            return None;
+        }
+        parts.push((id_start, pos, SdPart::Id));
+
+        while bytes.get(pos) == Some(&b' ') {
+            pos += 1;
+
+            let key_start = pos;
+            while bytes.get(pos).is_some_and(|&b| is_name_byte(b)) {
+                pos += 1;
+            }
+            if pos == key_start || bytes.get(pos) != Some(&b'=') || bytes.get(pos + 1) != Some(&b'"') {
+                return None;
+            }
+            parts.push((key_start, pos, SdPart::Key));
+            parts.push((pos, pos + 1, SdPart::Symbol));
+            pos += 1;
+
+            // Quoted value; `\"`, `\\` and `\]` are the escapes RFC 5424 allows.
+            let value_start = pos;
+            pos += 1;
+            loop {
+                match bytes.get(pos)? {
+                    b'\\' => pos += 2,
+                    b'"' => break,
+                    _ => pos += 1,
+                }
+            }
+            pos += 1;
+            parts.push((value_start, pos, SdPart::Value));
+        }
+
+        if bytes.get(pos) != Some(&b']') {
+            return None;
+        }
+        parts.push((pos, pos + 1, SdPart::Symbol));
+        pos += 1;
+    }
+
+    Some(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> SyslogFinder {
+        SyslogFinder::new(
+            SyslogConfig {
+                priority: Style::new().fg(Color::Magenta),
+                version: Style::new().faint(),
+                hostname: Style::new().fg(Color::Blue),
+                app: Style::new().fg(Color::Yellow),
+                pid: Style::new().fg(Color::Cyan),
+                msgid: Style::new().fg(Color::Magenta).italic(),
+                sd_id: Style::new().fg(Color::Green),
+                sd_param_key: Style::new().bold(),
+                sd_param_value: Style::new().fg(Color::Yellow).italic(),
+                symbol: Style::new().fg(Color::Red),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn rfc3164_without_priority() {
+        let texts = span_texts("Oct 11 22:14:15 mymachine su[1234]: 'su root' failed", &make_finder());
+        assert_eq!(texts, ["mymachine", "su", "[", "1234", "]:"]);
+    }
+
+    #[test]
+    fn rfc3164_with_priority_and_no_pid() {
+        let texts = span_texts("<34>Oct  1 22:14:15 mymachine kernel: eth0 down", &make_finder());
+        assert_eq!(texts, ["<", "34", ">", "mymachine", "kernel", ":"]);
+    }
+
+    #[test]
+    fn rfc3164_with_iso_timestamp() {
+        let input = "2026-10-18T14:02:11.123456+00:00 web-01 postfix/smtpd[4242]: connect from unknown";
+        let texts = span_texts(input, &make_finder());
+        assert_eq!(texts, ["web-01", "postfix/smtpd", "[", "4242", "]:"]);
+    }
+
+    #[test]
+    fn rfc5424_with_structured_data() {
+        let input = r#"<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 [exampleSDID@32473 iut="3" eventSource="Application"] An application event"#;
+        let texts = span_texts(input, &make_finder());
+        assert_eq!(
+            texts,
+            [
+                "<",
+                "165",
+                ">",
+                "1",
+                "mymachine.example.com",
+                "evntslog",
+                "ID47",
+                "[",
+                "exampleSDID@32473",
+                "iut",
+                "=",
+                r#""3""#,
+                "eventSource",
+                "=",
+                r#""Application""#,
+                "]"
+            ]
+        );
+    }
+
+    #[test]
+    fn rfc5424_with_multiple_elements_and_escapes() {
+        let input = r#"<14>1 2026-10-18T14:02:11Z host app 42 - [a@1 k="x\"]y"][b@2] msg"#;
+        let texts = span_texts(input, &make_finder());
+        assert!(texts.contains(&r#""x\"]y""#));
+        assert!(texts.contains(&"b@2"));
+        assert!(texts.contains(&"42"));
+    }
+
+    #[test]
+    fn rfc5424_without_structured_data() {
+        let input = "<14>1 2026-10-18T14:02:11Z host app - - - started";
+        let texts = span_texts(input, &make_finder());
+        assert_eq!(texts, ["<", "14", ">", "1", "host", "app"]);
+    }
+
+    #[test]
+    fn malformed_structured_data_no_match() {
+        let input = r#"<14>1 2026-10-18T14:02:11Z host app 42 - [a@1 k="unterminated] msg"#;
+        assert!(span_texts(input, &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn header_must_start_the_line() {
+        assert!(span_texts("seen: Oct 11 22:14:15 mymachine su[1234]: failed", &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn plain_log_line_no_match() {
+        assert!(span_texts("2024-09-14T07:57:30.659Z INFO Starting server", &make_finder()).is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+use super::build_regex;
+use memchr::memchr;
+use regex::Regex;
+
+use crate::core::config::WindowsPathConfig;
+
+use super::super::palette::{Palette, StyleId};
+use super::super::span::{Collector, Finder};
+
+/// A segment followed by a separator. Inner segments may hold single spaces
+/// and parentheses (`Program Files (x86)`); the final one may not, so a path
+/// ends before any trailing prose or closing bracket.
+const INNER_SEGMENT: &str = r"[\w.$~()-]+(?:\x20[\w.$~()-]+)*[\\/]";
+const LAST_SEGMENT: &str = r"[\w.$~-]+";
+
+/// Styles Windows drive paths (`C:\Users\build`, `D:/data`) and UNC paths
+/// (`\\server\share\file.txt`).
+#[derive(Debug, Clone)]
+pub(crate) struct WindowsPathFinder {
+    drive_path: Regex,
+    unc_path: Regex,
+    drive: StyleId,
+    segment: StyleId,
+    separator: StyleId,
+}
+
+impl WindowsPathFinder {
+    pub fn new(config: WindowsPathConfig, palette: &mut Palette) -> Self {
+        let drive_pattern = format!(r"\b[A-Za-z]:[\\/](?:{INNER_SEGMENT})*(?:{LAST_SEGMENT})?");
+        let drive_path = build_regex(&drive_pattern);
+
+        // `\\` must not continue a longer run of backslashes or a word, as in
+        // an escaped string like `a\\b`.
+        let unc_pattern = format!(r#"(?:^|[\s"'(=\[])(?P<path>\\\\[\w.-]+\\(?:{INNER_SEGMENT})*{LAST_SEGMENT})"#);
+        let unc_path = build_regex(&unc_pattern);
+
+        Self {
+            drive_path,
+            unc_path,
+            drive: palette.intern(config.drive),
+            segment: palette.intern(config.segment),
+            separator: palette.intern(config.separator),
+        }
+    }
+
+    /// Styles `bytes` (starting at `offset`) as alternating segments and separators.
+    fn push_segments(&self, bytes: &[u8], offset: usize, collector: &mut Collector) {
+        let mut seg_start = None;
+
+        for (i, &b) in bytes.iter().enumerate() {
+            if matches!(b, b'\\' | b'/') {
+                if let Some(start) = seg_start.take() {
+                    collector.push(offset + start, offset + i, self.segment);
+                }
+                collector.push(offset + i, offset + i + 1, self.separator);
+            } else if seg_start.is_none() {
+                seg_start = Some(i);
+            }
+        }
+
+        if let Some(start) = seg_start {
+            collector.push(offset + start, offset + bytes.len(), self.segment);
+        }
+    }
+}
+
+impl Finder for WindowsPathFinder {
+    fn find_spans(&self, input: &str, collector: &mut Collector) {
+        let bytes = input.as_bytes();
+
+        if memchr(b':', bytes).is_some() {
+            for m in self.drive_path.find_iter(input) {
+                collector.push(m.start(), m.start() + 2, self.drive);
+                self.push_segments(&bytes[m.start() + 2..m.end()], m.start() + 2, collector);
+            }
+        }
+
+        if memchr(b'\\', bytes).is_some() {
+            for caps in self.unc_path.captures_iter(input) {
+                let path = caps.name("path").unwrap();
+                self.push_segments(path.as_str().as_bytes(), path.start(), collector);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::span_texts;
+    use super::*;
+    use crate::style::{Color, Style};
+
+    fn make_finder() -> WindowsPathFinder {
+        WindowsPathFinder::new(
+            WindowsPathConfig {
+                drive: Style::new().fg(Color::Magenta),
+                segment: Style::new().fg(Color::Green),
+                separator: Style::new().fg(Color::Yellow),
+            },
+            &mut Palette::new(),
+        )
+    }
+
+    #[test]
+    fn drive_path() {
+        let texts = span_texts(r"compiling C:\Users\build\src\main.cs", &make_finder());
+        assert_eq!(
+            texts,
+            ["C:", "\\", "Users", "\\", "build", "\\", "src", "\\", "main.cs"]
+        );
+    }
+
+    #[test]
+    fn drive_path_with_forward_slashes() {
+        let texts = span_texts("D:/data/app.log", &make_finder());
+        assert_eq!(texts, ["D:", "/", "data", "/", "app.log"]);
+    }
+
+    #[test]
+    fn drive_root() {
+        assert_eq!(span_texts(r"free space on C:\ is low", &make_finder()), ["C:", "\\"]);
+    }
+
+    #[test]
+    fn unc_path() {
+        let texts = span_texts(r"copied to \\server\share\file.txt", &make_finder());
+        assert_eq!(texts, [r"\\", "server", "\\", "share", "\\", "file.txt"]);
+    }
+
+    #[test]
+    fn inner_segments_may_contain_spaces() {
+        let texts = span_texts(r"C:\Program Files (x86)\App\app.exe crashed", &make_finder());
+        assert_eq!(texts, ["C:", "\\", "Program Files (x86)", "\\", "App", "\\", "app.exe"]);
+    }
+
+    #[test]
+    fn path_stops_before_closing_bracket() {
+        let texts = span_texts(r"(see C:\logs\build.log)", &make_finder());
+        assert_eq!(texts, ["C:", "\\", "logs", "\\", "build.log"]);
+    }
+
+    #[test]
+    fn escaped_backslashes_no_match() {
+        assert!(span_texts(r#"regex "a\\b\\c""#, &make_finder()).is_empty());
+    }
+
+    #[test]
+    fn urls_and_times_no_match() {
+        assert!(span_texts("https://example.com/a/b", &make_finder()).is_empty());
+        assert!(span_texts("12:30:45 ratio 3:2", &make_finder()).is_empty());
+    }
+}
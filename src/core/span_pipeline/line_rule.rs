@@ -0,0 +1,86 @@
+use regex::{Error, Regex};
+
+use crate::core::config::LineConfig;
+
+use super::palette::{Palette, StyleId};
+
+/// Selects whole lines by words or a regex, or with `invert` the lines that
+/// match neither, and gives them a base style the spans are drawn over.
+#[derive(Debug, Clone)]
+pub(crate) struct LineRule {
+    regex: Option<Regex>,
+    invert: bool,
+    pub style: StyleId,
+}
+
+impl LineRule {
+    pub fn new(config: &LineConfig, palette: &mut Palette) -> Result<Self, Error> {
+        let mut alternatives = Vec::new();
+        if !config.words.is_empty() {
+            let words: Vec<String> = config.words.iter().map(|word| regex::escape(word)).collect();
+            alternatives.push(format!(r"\b(?:{})\b", words.join("|")));
+        }
+        if let Some(regex) = &config.regex {
+            alternatives.push(format!("(?:{regex})"));
+        }
+
+        let regex = if alternatives.is_empty() {
+            None
+        } else {
+            Some(Regex::new(&alternatives.join("|"))?)
+        };
+
+        Ok(Self {
+            regex,
+            invert: config.invert,
+            style: palette.intern(config.style),
+        })
+    }
+
+    pub fn selects(&self, line: &str) -> bool {
+        self.regex.as_ref().is_some_and(|regex| regex.is_match(line)) != self.invert
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(words: &[&str], regex: Option<&str>, invert: bool) -> LineRule {
+        let config = LineConfig {
+            words: words.iter().map(ToString::to_string).collect(),
+            regex: regex.map(str::to_string),
+            invert,
+            ..LineConfig::default()
+        };
+        LineRule::new(&config, &mut Palette::new()).unwrap()
+    }
+
+    #[test]
+    fn words_select_lines_containing_them_as_whole_words() {
+        let rule = rule(&["ERROR", "FATAL"], None, false);
+        assert!(rule.selects("12:00 ERROR disk full"));
+        assert!(rule.selects("FATAL"));
+        assert!(!rule.selects("no ERRORS here"));
+    }
+
+    #[test]
+    fn regex_selects_alongside_words() {
+        let rule = rule(&["ERROR"], Some(r"status=5\d\d"), false);
+        assert!(rule.selects("GET / status=503"));
+        assert!(rule.selects("ERROR"));
+        assert!(!rule.selects("GET / status=200"));
+    }
+
+    #[test]
+    fn invert_selects_the_other_lines() {
+        let rule = rule(&["ERROR"], None, true);
+        assert!(!rule.selects("ERROR disk full"));
+        assert!(rule.selects("INFO all good"));
+    }
+
+    #[test]
+    fn a_rule_without_words_or_regex_selects_nothing() {
+        assert!(!rule(&[], None, false).selects("anything"));
+    }
+}
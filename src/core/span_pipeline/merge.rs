@@ -1,5 +1,8 @@
-use super::palette::StyleId;
-use super::span::Span;
+use std::cmp::Reverse;
+
+use super::palette::{Composed, Palette, StyleId};
+use super::span::{Annotation, Span};
+use crate::style::Style;
 
 /// A resolved style assignment for a contiguous range.
 ///
@@ -7,12 +10,14 @@ use super::span::Span;
 /// padded match (a "badge" that survived merge intact). Fragments produced
 /// by a higher-priority finder splitting a padded match are emitted with
 /// `padded = false` — they keep their style but lose the surrounding spaces.
+/// `annotation` is kept or dropped by the same rule.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct ResolvedSpan {
     pub start: usize,
     pub end: usize,
     pub style: StyleId,
     pub padded: bool,
+    pub annotation: Option<Annotation>,
 }
 
 /// Merge overlapping spans into non-overlapping `ResolvedSpan`s.
@@ -46,28 +51,106 @@ pub(crate) fn merge_spans(input_len: usize, spans: &[Span]) -> Vec<ResolvedSpan>
         }
     }
 
+    encode_runs(&style_map, |(style, _)| style, spans)
+}
+
+/// Like [`merge_spans`], but composes instead of picking one winner: every
+/// byte gets the styles of all spans covering it, each higher-priority one
+/// drawn over the lower ones (see [`Style::over`]). Within a finder the first
+/// span pushed is on top, matching the winner `merge_spans` would pick.
+/// Combinations the palette lacks are interned into `composed`.
+pub(crate) fn merge_composed(
+    input_len: usize,
+    spans: &[Span],
+    palette: &Palette,
+    composed: &mut Composed,
+) -> Vec<ResolvedSpan> {
+    if spans.is_empty() {
+        return Vec::new();
+    }
+
+    // Phase 1: Paint from the bottom up. The stable sort keeps the reversed
+    // push order within a priority, so earlier pushes are painted last.
+    let mut order: Vec<&Span> = spans.iter().rev().collect();
+    order.sort_by_key(|span| Reverse(span.priority));
+
+    let mut styles: Vec<Option<Style>> = vec![None; input_len];
+    for span in order {
+        let top = palette.style(span.style);
+        for slot in &mut styles[span.start..span.end] {
+            *slot = Some(slot.map_or(top, |base| top.over(base)));
+        }
+    }
+
+    // Runs of one style are the common case, so only intern on a change.
+    let mut last: Option<(Style, StyleId)> = None;
+    let style_map: Vec<Option<StyleId>> = styles
+        .into_iter()
+        .map(|slot| {
+            let style = slot?;
+            match last {
+                Some((last_style, id)) if last_style == style => Some(id),
+                _ => {
+                    let id = palette.intern_composed(style, composed);
+                    last = Some((style, id));
+                    Some(id)
+                }
+            }
+        })
+        .collect();
+
+    encode_runs(&style_map, |id| id, spans)
+}
+
+/// Phase 2 of both merges: run-length encode a per-byte style map into
+/// `ResolvedSpan`s, setting `padded` and `annotation` for fragments whose
+/// endpoints exactly match such a span.
+fn encode_runs<T: Copy>(style_map: &[Option<T>], style_of: impl Fn(T) -> StyleId, spans: &[Span]) -> Vec<ResolvedSpan> {
+    let input_len = style_map.len();
+
     // Padded spans, sorted for the exact-match lookup in phase 2. Padding is
     // rare (only background-styled keywords), so this is usually empty and
     // allocates nothing.
     let mut padded_ranges: Vec<(usize, usize)> = spans.iter().filter(|s| s.padded).map(|s| (s.start, s.end)).collect();
     padded_ranges.sort_unstable();
 
-    // Phase 2: Run-length encode into ResolvedSpans, setting `padded` for
-    // fragments whose endpoints exactly match a padded span.
+    // Annotated spans, likewise rare (only epochs) and sorted for lookup.
+    let mut annotated_ranges: Vec<(usize, usize, Annotation)> = spans
+        .iter()
+        .filter_map(|s| s.annotation.map(|annotation| (s.start, s.end, annotation)))
+        .collect();
+    annotated_ranges.sort_unstable_by_key(|&(start, end, _)| (start, end));
+
+    // A run stops at the edges of an annotated span, so a neighbour in the
+    // same style does not swallow it and drop the annotation.
+    let mut edges: Vec<usize> = annotated_ranges
+        .iter()
+        .flat_map(|&(start, end, _)| [start, end])
+        .collect();
+    edges.sort_unstable();
+
     let mut result = Vec::new();
     let mut i = 0;
     while i < input_len {
-        if let Some((style, _)) = style_map[i] {
+        if let Some(style) = style_map[i].map(&style_of) {
             let start = i;
-            while i < input_len && style_map[i].is_some_and(|(s, _)| s == style) {
+            let stop = edges
+                .get(edges.partition_point(|&edge| edge <= start))
+                .map_or(input_len, |&edge| edge.min(input_len));
+            while i < stop && style_map[i].is_some_and(|slot| style_of(slot) == style) {
                 i += 1;
             }
             let padded = padded_ranges.binary_search(&(start, i)).is_ok();
+            let annotation = annotated_ranges
+                .binary_search_by_key(&(start, i), |&(start, end, _)| (start, end))
+                .ok()
+                .map(|index| annotated_ranges[index].2);
             result.push(ResolvedSpan {
                 start,
                 end: i,
                 style,
                 padded,
+                annotation,
             });
         } else {
             i += 1;
@@ -100,6 +183,14 @@ mod tests {
             style,
             priority,
             padded: true,
+            annotation: None,
+        }
+    }
+
+    fn annotated_span(start: usize, end: usize, style: StyleId, priority: u16) -> Span {
+        Span {
+            annotation: Some(Annotation::Epoch(style)),
+            ..Span::new(start, end, style, priority)
         }
     }
 
@@ -115,6 +206,7 @@ mod tests {
             end,
             style,
             padded: false,
+            annotation: None,
         }
     }
 
@@ -124,6 +216,7 @@ mod tests {
             end,
             style,
             padded: true,
+            annotation: None,
         }
     }
 
@@ -207,4 +300,83 @@ mod tests {
         let result = merge_spans(13, &spans);
         assert_eq!(result, vec![resolved(0, 4, blue()), padded(4, 13, red())]);
     }
+
+    #[test]
+    fn intact_annotated_span_keeps_annotation() {
+        let spans = [annotated_span(2, 12, red(), 0)];
+        let result = merge_spans(12, &spans);
+        assert_eq!(result[0].annotation, Some(Annotation::Epoch(red())));
+    }
+
+    #[test]
+    fn fragmented_annotated_span_loses_annotation() {
+        let spans = [Span::new(4, 5, blue(), 0), annotated_span(2, 12, red(), 1)];
+        let result = merge_spans(12, &spans);
+        assert!(result.iter().all(|span| span.annotation.is_none()));
+    }
+
+    #[test]
+    fn annotated_span_next_to_the_same_style_keeps_annotation() {
+        let spans = [Span::new(0, 2, red(), 0), annotated_span(2, 12, red(), 1)];
+        let result = merge_spans(14, &spans);
+        assert_eq!(
+            result,
+            vec![
+                resolved(0, 2, red()),
+                ResolvedSpan {
+                    annotation: Some(Annotation::Epoch(red())),
+                    ..resolved(2, 12, red())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn composed_overlap_layers_the_lower_priority_style_underneath() {
+        use crate::style::Color;
+
+        let mut palette = Palette::new();
+        let cyan = palette.intern(Style::new().fg(Color::Cyan));
+        let italic = palette.intern(Style::new().italic().fg(Color::Yellow));
+        let mut composed = Composed::new();
+
+        // A number (priority 0) inside a quote (priority 1).
+        let spans = [Span::new(3, 5, cyan, 0), Span::new(0, 8, italic, 1)];
+        let result = merge_composed(8, &spans, &palette, &mut composed);
+
+        let inner = palette.intern_composed(Style::new().italic().fg(Color::Cyan), &mut composed);
+        assert_eq!(
+            result,
+            vec![resolved(0, 3, italic), resolved(3, 5, inner), resolved(5, 8, italic)]
+        );
+        assert_eq!(&palette.with(&composed)[inner], "\x1b[3;36m");
+    }
+
+    #[test]
+    fn composed_same_priority_overlap_keeps_the_first_pushed_on_top() {
+        use crate::style::Color;
+
+        let mut palette = Palette::new();
+        let first = palette.intern(Style::new().fg(Color::Red));
+        let second = palette.intern(Style::new().fg(Color::Blue).bold());
+        let mut composed = Composed::new();
+
+        let spans = [Span::new(0, 4, first, 0), Span::new(2, 6, second, 0)];
+        let result = merge_composed(6, &spans, &palette, &mut composed);
+
+        let bold_red = palette.intern_composed(Style::new().fg(Color::Red).bold(), &mut composed);
+        assert_eq!(
+            result,
+            vec![resolved(0, 2, first), resolved(2, 4, bold_red), resolved(4, 6, second)]
+        );
+    }
+
+    #[test]
+    fn composed_intact_padded_span_keeps_padded_flag() {
+        let mut palette = Palette::new();
+        let style = palette.intern(Style::new().bold());
+        let spans = [padded_span(2, 7, style, 0)];
+        let result = merge_composed(10, &spans, &palette, &mut Composed::new());
+        assert_eq!(result, vec![padded(2, 7, style)]);
+    }
 }
@@ -1,4 +1,5 @@
 pub(crate) mod finders;
+pub(crate) mod line_rule;
 pub(crate) mod merge;
 pub(crate) mod palette;
 pub(crate) mod render;
@@ -7,9 +8,11 @@ pub(crate) mod span;
 use std::borrow::Cow;
 use std::cell::RefCell;
 
-use merge::merge_spans;
-use palette::Palette;
-use render::render;
+use finders::delta::DeltaFinder;
+use line_rule::LineRule;
+use merge::{merge_composed, merge_spans};
+use palette::{Composed, Palette};
+use render::{render, render_plain};
 use span::{Collector, Finder, Span};
 
 /// Per-call scratch buffers reused across `apply` invocations on the same
@@ -19,6 +22,7 @@ use span::{Collector, Finder, Span};
 struct Scratch {
     collector: Collector,
     all_spans: Vec<Span>,
+    composed: Composed,
 }
 
 impl Scratch {
@@ -26,6 +30,7 @@ impl Scratch {
         Self {
             collector: Collector::new(),
             all_spans: Vec::new(),
+            composed: Composed::new(),
         }
     }
 }
@@ -43,12 +48,61 @@ thread_local! {
 #[derive(Debug, Clone)]
 pub(crate) struct Pipeline {
     finders: Vec<Box<dyn Finder>>,
+    line_rules: Vec<LineRule>,
+    prefix: Option<DeltaFinder>,
+    compose: bool,
+    plain: bool,
     palette: Palette,
 }
 
 impl Pipeline {
     pub(crate) fn new(finders: Vec<Box<dyn Finder>>, palette: Palette) -> Self {
-        Self { finders, palette }
+        Self {
+            finders,
+            line_rules: Vec::new(),
+            prefix: None,
+            compose: false,
+            plain: false,
+            palette,
+        }
+    }
+
+    /// Styles whole lines with the first rule that selects them.
+    pub(crate) fn with_line_rules(mut self, line_rules: Vec<LineRule>) -> Self {
+        self.line_rules = line_rules;
+        self
+    }
+
+    /// Treats the delta column as a prefix: the finders and line rules see
+    /// only the text after it, as they would without `--deltas`.
+    pub(crate) fn with_prefix(mut self, prefix: Option<DeltaFinder>) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Layers overlapping styles instead of letting the highest priority
+    /// replace the rest; see [`merge_composed`].
+    pub(crate) const fn with_composition(mut self, compose: bool) -> Self {
+        self.compose = compose;
+        self
+    }
+
+    /// Writes the annotations the spans carry but none of their styles.
+    pub(crate) const fn with_plain_output(mut self, plain: bool) -> Self {
+        self.plain = plain;
+        self
+    }
+
+    /// Shows every finder a line ahead of highlighting, in stream order.
+    pub(crate) fn observe(&self, input: &str) {
+        let body = &input[self.prefix_len(input)..];
+        for finder in &self.finders {
+            finder.observe(body);
+        }
+    }
+
+    fn prefix_len(&self, input: &str) -> usize {
+        self.prefix.as_ref().map_or(0, |prefix| prefix.prefix_len(input))
     }
 
     /// Apply all finders sequentially, merge, render.
@@ -60,6 +114,9 @@ impl Pipeline {
             s.all_spans.clear();
             s.collector.reset();
 
+            let offset = self.prefix_len(input);
+            let body = &input[offset..];
+
             for (priority, finder) in self.finders.iter().enumerate() {
                 // Priority is the finder's index; it lives in a `u16` slot in
                 // merge's byte-map, so confine the one narrowing cast here.
@@ -70,12 +127,54 @@ impl Pipeline {
                 #[allow(clippy::cast_possible_truncation)]
                 let priority = priority as u16;
 
-                finder.find_spans(input, &mut s.collector);
+                finder.find_spans(body, &mut s.collector);
                 s.collector.drain_into(&mut s.all_spans, priority);
             }
 
+            if let Some(prefix) = &self.prefix {
+                for span in &mut s.all_spans {
+                    span.start += offset;
+                    span.end += offset;
+                }
+                // The column lies outside every finder's spans; it only ever
+                // overlaps the composed line style, which sits below it.
+                prefix.find_spans(&input[..offset], &mut s.collector);
+                s.collector.drain_into(&mut s.all_spans, u16::MAX - 1);
+            }
+
+            if self.plain {
+                let resolved = merge_spans(input.len(), &s.all_spans);
+                return render_plain(input, &resolved);
+            }
+
+            let line = self
+                .line_rules
+                .iter()
+                .find(|rule| rule.selects(body))
+                .map(|rule| rule.style);
+
+            if self.compose {
+                // The line style becomes the bottom layer of every span.
+                if let Some(style) = line {
+                    s.all_spans.push(Span {
+                        start: 0,
+                        end: input.len(),
+                        style,
+                        priority: u16::MAX,
+                        padded: false,
+                        annotation: None,
+                    });
+                }
+                s.composed.clear();
+                let resolved = merge_composed(input.len(), &s.all_spans, &self.palette, &mut s.composed);
+                // The spans already carry the line style, but an annotation
+                // is text of render's own and needs it passed in.
+                let line = line.filter(|_| resolved.iter().any(|span| span.annotation.is_some()));
+                return render(input, &resolved, line, &self.palette.with(&s.composed));
+            }
+
             let resolved = merge_spans(input.len(), &s.all_spans);
-            render(input, &resolved, &self.palette)
+            render(input, &resolved, line, &self.palette)
         })
     }
 }
@@ -83,9 +182,12 @@ impl Pipeline {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::config::{KeywordConfig, NumberConfig, QuoteConfig};
+    use crate::core::config::{
+        DateTimeConfig, EpochConfig, KeywordConfig, LineConfig, NumberConfig, QuoteConfig, RegexConfig,
+    };
     use crate::core::tests::escape_code_converter::ConvertEscapeCodes;
     use crate::style::{Color, Style};
+    use finders::epoch::EpochFinder;
     use finders::keyword::KeywordFinder;
     use finders::number::NumberFinder;
     use finders::quote::QuoteFinder;
@@ -95,6 +197,7 @@ mod tests {
         KeywordConfig {
             words: words.iter().map(ToString::to_string).collect(),
             style,
+            ..KeywordConfig::default()
         }
     }
 
@@ -102,6 +205,7 @@ mod tests {
         NumberFinder::new(
             NumberConfig {
                 style: Style::new().fg(Color::Cyan),
+                ..NumberConfig::default()
             },
             palette,
         )
@@ -314,7 +418,12 @@ mod tests {
         // Regex (priority 0) overlaps the start of a padded keyword (priority 1).
         // The keyword fragment should NOT get badge padding.
         let mut palette = Palette::new();
-        let regex = RegexFinder::new("ERR", Style::new().fg(Color::Cyan), &mut palette).unwrap();
+        let config = RegexConfig {
+            regex: "ERR".to_string(),
+            style: Style::new().fg(Color::Cyan),
+            ..RegexConfig::default()
+        };
+        let regex = RegexFinder::new(&config, &mut palette).unwrap();
         let keyword = keyword(&[kw(&["ERROR"], Style::new().on(Color::Red))], &mut palette);
         let highlighter = Pipeline::new(vec![Box::new(regex), Box::new(keyword)], palette);
 
@@ -384,4 +493,35 @@ mod tests {
         // The 42 is still highlighted; ANSI codes are treated as opaque text
         assert!(readable.contains("[cyan]42[reset]"));
     }
+
+    #[test]
+    fn composed_line_style_runs_under_an_annotation() {
+        let mut palette = Palette::new();
+        let epoch = EpochFinder::new(
+            EpochConfig {
+                date_time: DateTimeConfig {
+                    date: Style::new().fg(Color::Red),
+                    separator: Style::new().fg(Color::Green),
+                    ..DateTimeConfig::default()
+                },
+                annotate: true,
+            },
+            &mut palette,
+        );
+        let line = LineConfig {
+            words: vec!["ok".to_string()],
+            style: Style::new().on(Color::Yellow),
+            ..LineConfig::default()
+        };
+        let line_rules = vec![LineRule::new(&line, &mut palette).unwrap()];
+        let highlighter = Pipeline::new(vec![Box::new(epoch)], palette)
+            .with_line_rules(line_rules)
+            .with_composition(true);
+
+        let result = highlighter.apply("ts=1729245600 ok");
+        assert_eq!(
+            result.to_string().convert_escape_codes(),
+            "[bg_yellow][bg_yellow]ts=[reset][bg_yellow]\x1b[43;31m1729245600[reset][bg_yellow] [green](2024-10-18T10:00:00.000Z)[reset][bg_yellow][bg_yellow] ok[reset]"
+        );
+    }
 }
@@ -6,7 +6,7 @@ use crate::style::Style;
 
 /// A compact handle to an interned [`Style`]: an index into the [`Palette`]'s
 /// precomputed ANSI prefixes. Spans carry this instead of the style itself.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) struct StyleId(u16);
 
 #[cfg(test)]
@@ -27,22 +27,58 @@ impl StyleId {
 #[derive(Debug, Clone)]
 pub(crate) struct Palette {
     prefixes: Vec<String>,
+    styles: Vec<Style>,
 }
 
 impl Palette {
     pub const fn new() -> Self {
-        Self { prefixes: Vec::new() }
+        Self {
+            prefixes: Vec::new(),
+            styles: Vec::new(),
+        }
     }
 
     pub fn intern(&mut self, style: Style) -> StyleId {
         let prefix = NuStyle::from(style).prefix().to_string();
         let index = self.prefixes.iter().position(|p| *p == prefix).unwrap_or_else(|| {
             self.prefixes.push(prefix);
+            self.styles.push(style);
             self.prefixes.len() - 1
         });
 
         StyleId(u16::try_from(index).expect("distinct style count exceeds u16 range"))
     }
+
+    /// The style `id` was interned from.
+    pub fn style(&self, id: StyleId) -> Style {
+        self.styles[id.0 as usize]
+    }
+
+    /// Interns a style composed while merging. Styles the palette already
+    /// renders keep their id; new ones are numbered after the palette's own,
+    /// in `composed`, which lives only as long as one `apply`.
+    pub fn intern_composed(&self, style: Style, composed: &mut Composed) -> StyleId {
+        let prefix = NuStyle::from(style).prefix().to_string();
+        let index = if let Some(index) = self.prefixes.iter().position(|p| *p == prefix) {
+            index
+        } else {
+            let index = composed.prefixes.iter().position(|p| *p == prefix).unwrap_or_else(|| {
+                composed.prefixes.push(prefix);
+                composed.prefixes.len() - 1
+            });
+            self.prefixes.len() + index
+        };
+
+        StyleId(u16::try_from(index).expect("distinct style count exceeds u16 range"))
+    }
+
+    /// The palette extended by `composed`, for rendering.
+    pub fn with<'a>(&'a self, composed: &'a Composed) -> Extended<'a> {
+        Extended {
+            palette: self,
+            composed,
+        }
+    }
 }
 
 impl Index<StyleId> for Palette {
@@ -53,6 +89,43 @@ impl Index<StyleId> for Palette {
     }
 }
 
+/// Styles composed from overlapping spans during one `apply`. Kept in the
+/// per-thread scratch and cleared between calls, so the shared palette stays
+/// frozen.
+#[derive(Debug, Default)]
+pub(crate) struct Composed {
+    prefixes: Vec<String>,
+}
+
+impl Composed {
+    pub const fn new() -> Self {
+        Self { prefixes: Vec::new() }
+    }
+
+    pub fn clear(&mut self) {
+        self.prefixes.clear();
+    }
+}
+
+/// A [`Palette`] together with the styles composed for the current input.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Extended<'a> {
+    palette: &'a Palette,
+    composed: &'a Composed,
+}
+
+impl Index<StyleId> for Extended<'_> {
+    type Output = str;
+
+    fn index(&self, id: StyleId) -> &str {
+        let index = id.0 as usize;
+        match index.checked_sub(self.palette.prefixes.len()) {
+            Some(index) => &self.composed.prefixes[index],
+            None => &self.palette.prefixes[index],
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,6 +142,25 @@ mod tests {
         assert_ne!(a, b);
     }
 
+    #[test]
+    fn composed_styles_reuse_palette_ids_and_number_new_ones_after_them() {
+        let mut palette = Palette::new();
+        let red = palette.intern(Style::new().fg(Color::Red));
+        let italic = palette.intern(Style::new().italic());
+        let mut composed = Composed::new();
+
+        assert_eq!(palette.intern_composed(Style::new().fg(Color::Red), &mut composed), red);
+
+        let red_italic = Style::new().fg(Color::Red).italic();
+        let id = palette.intern_composed(red_italic, &mut composed);
+        assert_ne!(id, red);
+        assert_ne!(id, italic);
+        assert_eq!(palette.intern_composed(red_italic, &mut composed), id);
+        assert_eq!(palette.style(red), Style::new().fg(Color::Red));
+        assert_eq!(&palette.with(&composed)[id], "\x1b[3;31m");
+        assert_eq!(&palette.with(&composed)[red], "\x1b[31m");
+    }
+
     #[test]
     fn indexing_resolves_the_precomputed_prefix() {
         let mut palette = Palette::new();
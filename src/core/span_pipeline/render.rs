@@ -1,7 +1,11 @@
 use std::borrow::Cow;
+use std::fmt::Write;
+use std::ops::Index;
 
+use super::finders::epoch;
 use super::merge::ResolvedSpan;
-use super::palette::Palette;
+use super::palette::StyleId;
+use super::span::Annotation;
 
 const RESET: &str = "\x1b[0m";
 
@@ -10,16 +14,28 @@ const RESET: &str = "\x1b[0m";
 /// Each span carries its own `padded` flag; render is a straight walk with
 /// no cross-reference to a sibling buffer. The atomicity rule (a padded
 /// match only keeps its surrounding spaces if merge preserved it whole) is
-/// enforced upstream in `merge_spans` — render just reads the flag.
+/// enforced upstream in `merge_spans` — render just reads the flag. An
+/// annotation is written after the span's reset, separated by a space.
 ///
-/// Returns `Cow::Borrowed` if no spans exist (zero allocation).
-pub(crate) fn render<'a>(input: &'a str, spans: &[ResolvedSpan], palette: &Palette) -> Cow<'a, str> {
-    if spans.is_empty() {
+/// A `line` style is laid under the whole input: it opens the output and is
+/// reopened after every span's reset, so spans only override the attributes
+/// they set (a keyword's foreground over a line's background).
+///
+/// Returns `Cow::Borrowed` if no spans or line style exist (zero allocation).
+pub(crate) fn render<'a, P>(input: &'a str, spans: &[ResolvedSpan], line: Option<StyleId>, palette: &P) -> Cow<'a, str>
+where
+    P: Index<StyleId, Output = str> + ?Sized,
+{
+    if input.is_empty() || (spans.is_empty() && line.is_none()) {
         return Cow::Borrowed(input);
     }
+    let line = line.map(|style| &palette[style]);
 
     let mut output = String::with_capacity(input.len() + spans.len() * 16);
     let mut pos = 0;
+    if let Some(line) = line {
+        output.push_str(line);
+    }
 
     for span in spans {
         if pos < span.start {
@@ -36,24 +52,82 @@ pub(crate) fn render<'a>(input: &'a str, spans: &[ResolvedSpan], palette: &Palet
         }
         output.push_str(RESET);
 
+        if let Some(annotation) = span.annotation {
+            // The annotation is part of the line, so it sits on its style too.
+            if let Some(line) = line {
+                output.push_str(line);
+            }
+            push_annotation(&mut output, annotation, &input[span.start..span.end], palette);
+        }
+
         pos = span.end;
+        if let Some(line) = line
+            && pos < input.len()
+        {
+            output.push_str(line);
+        }
     }
 
     if pos < input.len() {
         output.push_str(&input[pos..]);
+        if line.is_some() {
+            output.push_str(RESET);
+        }
     }
 
     Cow::Owned(output)
 }
 
+/// Render the original input with only the annotations written out, for
+/// output without styles. Returns `Cow::Borrowed` when no span has one.
+pub(crate) fn render_plain<'a>(input: &'a str, spans: &[ResolvedSpan]) -> Cow<'a, str> {
+    let mut output = String::new();
+    let mut pos = 0;
+
+    for span in spans {
+        let Some(annotation) = span.annotation else {
+            continue;
+        };
+        let text = &input[span.start..span.end];
+        match annotation {
+            Annotation::Epoch(_) => {
+                if let Some(timestamp) = epoch::decode(text) {
+                    output.push_str(&input[pos..span.end]);
+                    let _ = write!(output, " ({timestamp})");
+                    pos = span.end;
+                }
+            }
+        }
+    }
+
+    if pos == 0 {
+        return Cow::Borrowed(input);
+    }
+    output.push_str(&input[pos..]);
+
+    Cow::Owned(output)
+}
+
+fn push_annotation<P>(output: &mut String, annotation: Annotation, text: &str, palette: &P)
+where
+    P: Index<StyleId, Output = str> + ?Sized,
+{
+    match annotation {
+        Annotation::Epoch(style) => {
+            if let Some(timestamp) = epoch::decode(text) {
+                let _ = write!(output, " {}({timestamp}){RESET}", &palette[style]);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::span_pipeline::palette::Palette;
     use crate::core::tests::escape_code_converter::ConvertEscapeCodes;
     use crate::style::{Color, Style};
 
-    use super::super::palette::StyleId;
-
     fn red(palette: &mut Palette) -> StyleId {
         palette.intern(Style::new().fg(Color::Red))
     }
@@ -76,6 +150,7 @@ mod tests {
             end,
             style,
             padded: false,
+            annotation: None,
         }
     }
 
@@ -85,13 +160,14 @@ mod tests {
             end,
             style,
             padded: true,
+            annotation: None,
         }
     }
 
     #[test]
     fn empty_spans_returns_borrowed() {
         let input = "hello world";
-        let result = render(input, &[], &Palette::new());
+        let result = render(input, &[], None, &Palette::new());
         assert!(matches!(result, Cow::Borrowed(_)));
         assert_eq!(&*result, "hello world");
     }
@@ -101,7 +177,7 @@ mod tests {
         let input = "hello world";
         let mut palette = Palette::new();
         let red = red(&mut palette);
-        let result = render(input, &[span(6, 11, red)], &palette);
+        let result = render(input, &[span(6, 11, red)], None, &palette);
         assert_eq!(result.to_string().convert_escape_codes(), "hello [red]world[reset]");
     }
 
@@ -110,7 +186,7 @@ mod tests {
         let input = "abc def ghi";
         let mut palette = Palette::new();
         let red = red(&mut palette);
-        let result = render(input, &[span(0, 3, red), span(8, 11, red)], &palette);
+        let result = render(input, &[span(0, 3, red), span(8, 11, red)], None, &palette);
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "[red]abc[reset] def [red]ghi[reset]"
@@ -123,7 +199,7 @@ mod tests {
         let mut palette = Palette::new();
         let red = red(&mut palette);
         let green = green(&mut palette);
-        let result = render(input, &[span(0, 3, red), span(3, 6, green)], &palette);
+        let result = render(input, &[span(0, 3, red), span(3, 6, green)], None, &palette);
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "[red]abc[reset][green]def[reset]"
@@ -135,7 +211,7 @@ mod tests {
         let input = "x ERROR y";
         let mut palette = Palette::new();
         let bg_red = bg_red(&mut palette);
-        let result = render(input, &[padded(2, 7, bg_red)], &palette);
+        let result = render(input, &[padded(2, 7, bg_red)], None, &palette);
         assert_eq!(result.to_string().convert_escape_codes(), "x [bg_red] ERROR [reset] y");
     }
 
@@ -144,7 +220,7 @@ mod tests {
         let input = "ERROR rest";
         let mut palette = Palette::new();
         let bg_red = bg_red(&mut palette);
-        let result = render(input, &[padded(0, 5, bg_red)], &palette);
+        let result = render(input, &[padded(0, 5, bg_red)], None, &palette);
         assert_eq!(result.to_string().convert_escape_codes(), "[bg_red] ERROR [reset] rest");
     }
 
@@ -153,7 +229,7 @@ mod tests {
         let input = "prefix ERROR";
         let mut palette = Palette::new();
         let bg_red = bg_red(&mut palette);
-        let result = render(input, &[padded(7, 12, bg_red)], &palette);
+        let result = render(input, &[padded(7, 12, bg_red)], None, &palette);
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "prefix [bg_red] ERROR [reset]"
@@ -165,7 +241,7 @@ mod tests {
         let input = "x ERROR y";
         let mut palette = Palette::new();
         let red = red(&mut palette);
-        let result = render(input, &[span(2, 7, red)], &palette);
+        let result = render(input, &[span(2, 7, red)], None, &palette);
         assert_eq!(result.to_string().convert_escape_codes(), "x [red]ERROR[reset] y");
     }
 
@@ -175,7 +251,12 @@ mod tests {
         let mut palette = Palette::new();
         let bg_yellow = bg_yellow(&mut palette);
         let bg_red = bg_red(&mut palette);
-        let result = render(input, &[padded(0, 4, bg_yellow), padded(10, 15, bg_red)], &palette);
+        let result = render(
+            input,
+            &[padded(0, 4, bg_yellow), padded(10, 15, bg_red)],
+            None,
+            &palette,
+        );
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "[bg_yellow] WARN [reset] then [bg_red] ERROR [reset] end"
@@ -191,10 +272,85 @@ mod tests {
         let mut palette = Palette::new();
         let bg_red = bg_red(&mut palette);
         let green = green(&mut palette);
-        let result = render(input, &[span(2, 5, bg_red), span(5, 7, green)], &palette);
+        let result = render(input, &[span(2, 5, bg_red), span(5, 7, green)], None, &palette);
         assert_eq!(
             result.to_string().convert_escape_codes(),
             "x [bg_red]ERR[reset][green]OR[reset] y"
         );
     }
+
+    #[test]
+    fn epoch_annotation_follows_the_span() {
+        let input = "ts=1729245600 ok";
+        let mut palette = Palette::new();
+        let red = red(&mut palette);
+        let green = green(&mut palette);
+        let epoch = ResolvedSpan {
+            annotation: Some(Annotation::Epoch(green)),
+            ..span(3, 13, red)
+        };
+        let result = render(input, &[epoch], None, &palette);
+        assert_eq!(
+            result.to_string().convert_escape_codes(),
+            "ts=[red]1729245600[reset] [green](2024-10-18T10:00:00.000Z)[reset] ok"
+        );
+    }
+
+    #[test]
+    fn plain_render_keeps_only_the_annotations() {
+        let input = "ts=1729245600 ok 42";
+        let mut palette = Palette::new();
+        let red = red(&mut palette);
+        let green = green(&mut palette);
+        let epoch = ResolvedSpan {
+            annotation: Some(Annotation::Epoch(green)),
+            ..span(3, 13, red)
+        };
+
+        assert_eq!(
+            render_plain(input, &[epoch, span(17, 19, red)]),
+            "ts=1729245600 (2024-10-18T10:00:00.000Z) ok 42"
+        );
+        assert!(matches!(render_plain(input, &[span(17, 19, red)]), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn line_style_is_reopened_after_every_span() {
+        let input = "an ERROR here";
+        let mut palette = Palette::new();
+        let red = red(&mut palette);
+        let bg_yellow = bg_yellow(&mut palette);
+        let result = render(input, &[span(3, 8, red)], Some(bg_yellow), &palette);
+        assert_eq!(
+            result.to_string().convert_escape_codes(),
+            "[bg_yellow]an [red]ERROR[reset][bg_yellow] here[reset]"
+        );
+    }
+
+    #[test]
+    fn line_style_runs_under_an_annotation() {
+        let input = "ts=1729245600 ok";
+        let mut palette = Palette::new();
+        let red = red(&mut palette);
+        let green = green(&mut palette);
+        let bg_yellow = bg_yellow(&mut palette);
+        let epoch = ResolvedSpan {
+            annotation: Some(Annotation::Epoch(green)),
+            ..span(3, 13, red)
+        };
+        let result = render(input, &[epoch], Some(bg_yellow), &palette);
+        assert_eq!(
+            result.to_string().convert_escape_codes(),
+            "[bg_yellow]ts=[red]1729245600[reset][bg_yellow] [green](2024-10-18T10:00:00.000Z)[reset][bg_yellow] ok[reset]"
+        );
+    }
+
+    #[test]
+    fn line_style_alone_covers_the_input() {
+        let mut palette = Palette::new();
+        let bg_yellow = bg_yellow(&mut palette);
+        let result = render("plain", &[], Some(bg_yellow), &palette);
+        assert_eq!(result.to_string().convert_escape_codes(), "[bg_yellow]plain[reset]");
+        assert!(matches!(render("", &[], Some(bg_yellow), &palette), Cow::Borrowed("")));
+    }
 }
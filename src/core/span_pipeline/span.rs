@@ -11,7 +11,8 @@ use super::palette::StyleId;
 /// [`Collector::drain_into`]).
 ///
 /// `padded` asks render to surround the span text with a space on each side (a
-/// "badge"), but only if merge preserves the span intact.
+/// "badge"), but only if merge preserves the span intact. `annotation` follows
+/// the same rule.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct Span {
     pub start: usize,
@@ -19,6 +20,15 @@ pub(crate) struct Span {
     pub style: StyleId,
     pub priority: u16,
     pub padded: bool,
+    pub annotation: Option<Annotation>,
+}
+
+/// Text render appends after a span, derived from the span's own text and
+/// styled separately from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Annotation {
+    /// The span is a Unix epoch; append it decoded as an ISO 8601 UTC time.
+    Epoch(StyleId),
 }
 
 #[cfg(test)]
@@ -30,6 +40,7 @@ impl Span {
             style,
             priority,
             padded: false,
+            annotation: None,
         }
     }
 }
@@ -48,17 +59,23 @@ impl Collector {
     /// Push a span. If it is contiguous with the last span and shares its style
     /// and padding, extend the last span rather than pushing a new one.
     pub fn push(&mut self, start: usize, end: usize, style: StyleId) {
-        self.push_impl(start, end, style, false);
+        self.push_impl(start, end, style, false, None);
     }
 
     /// Push a span with padding. Render will insert a space before and after
     /// the span text, inside the ANSI color (creating a "badge" effect for
     /// keywords with background colors).
     pub fn push_padded(&mut self, start: usize, end: usize, style: StyleId) {
-        self.push_impl(start, end, style, true);
+        self.push_impl(start, end, style, true, None);
+    }
+
+    /// Push a span that render follows with `annotation`. Annotated spans never
+    /// coalesce, since the annotation describes exactly this span's text.
+    pub fn push_annotated(&mut self, start: usize, end: usize, style: StyleId, annotation: Annotation) {
+        self.push_impl(start, end, style, false, Some(annotation));
     }
 
-    fn push_impl(&mut self, start: usize, end: usize, style: StyleId, padded: bool) {
+    fn push_impl(&mut self, start: usize, end: usize, style: StyleId, padded: bool, annotation: Option<Annotation>) {
         if start >= end {
             return;
         }
@@ -68,6 +85,8 @@ impl Collector {
         // merge decides padding by whole-span extent — a merged span would
         // either over- or under-pad.
         if let Some(last) = self.spans.last_mut()
+            && annotation.is_none()
+            && last.annotation.is_none()
             && last.style == style
             && last.padded == padded
             && last.end == start
@@ -81,6 +100,7 @@ impl Collector {
             style,
             priority: 0,
             padded,
+            annotation,
         });
     }
 
@@ -109,6 +129,10 @@ impl Collector {
 /// into the collector.
 pub(crate) trait Finder: std::fmt::Debug + Sync + Send + BoxedCloneFinder {
     fn find_spans(&self, input: &str, collector: &mut Collector);
+
+    /// Sees the lines in stream order, ahead of highlighting; see
+    /// [`crate::Highlighter::observe`]. Most finders need nothing from it.
+    fn observe(&self, _input: &str) {}
 }
 
 /// Object-safe clone for boxed finders, blanket-implemented so finder
@@ -204,4 +228,17 @@ mod tests {
         assert!(!spans[0].padded);
         assert!(spans[1].padded);
     }
+
+    #[test]
+    fn does_not_coalesce_annotated_spans() {
+        let style = StyleId::new(0);
+        let mut collector = Collector::new();
+        collector.push(0, 1, style);
+        collector.push_annotated(1, 2, style, Annotation::Epoch(style));
+        collector.push(2, 3, style);
+
+        let spans = collector.into_spans();
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[1].annotation, Some(Annotation::Epoch(style)));
+    }
 }
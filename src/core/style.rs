@@ -70,6 +70,19 @@ impl Style {
     pub const fn on(&self, bg: Color) -> Style {
         Style { bg: Some(bg), ..*self }
     }
+
+    /// This style drawn over `base`: colors it leaves unset show through, and
+    /// the attributes of both apply.
+    pub(crate) fn over(self, base: Style) -> Style {
+        Style {
+            fg: self.fg.or(base.fg),
+            bg: self.bg.or(base.bg),
+            bold: self.bold || base.bold,
+            faint: self.faint || base.faint,
+            italic: self.italic || base.italic,
+            underline: self.underline || base.underline,
+        }
+    }
 }
 
 /// Available ANSI colors for terminal output.
@@ -0,0 +1,427 @@
+//! Timestamps as the date and time highlighters see them: a
+//! [`TimestampScanner`] locates full timestamps in log text and decodes them
+//! into [`Timestamp`]s, which format back out in any [`UtcOffset`].
+//!
+//! There is no time zone database, so zones are fixed offsets from UTC.
+
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use regex::Regex;
+
+use super::span_pipeline::finders::build_regex;
+use super::span_pipeline::finders::date_dash::YEAR_FIRST_DATE;
+use super::span_pipeline::finders::date_time::CLOCK_TIME;
+
+/// A point in time since the Unix epoch, always in UTC.
+///
+/// Displays as ISO 8601 with millisecond precision, e.g.
+/// `2024-10-18T10:00:00.123Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp {
+    secs: i64,
+    nanos: u32,
+}
+
+impl Timestamp {
+    /// A timestamp `nanos` nanoseconds after the Unix epoch.
+    #[must_use]
+    pub fn from_unix_nanos(nanos: u128) -> Option<Self> {
+        Some(Self {
+            secs: i64::try_from(nanos / 1_000_000_000).ok()?,
+            nanos: u32::try_from(nanos % 1_000_000_000).ok()?,
+        })
+    }
+
+    /// A timestamp `micros` microseconds after the Unix epoch, the unit of
+    /// the journal's `__REALTIME_TIMESTAMP`.
+    #[must_use]
+    pub fn from_unix_micros(micros: u64) -> Self {
+        Self::from_unix_nanos(u128::from(micros) * 1_000).expect("microseconds fit the nanosecond range")
+    }
+
+    /// Whole seconds since the Unix epoch, negative before 1970.
+    #[must_use]
+    pub const fn unix_secs(&self) -> i64 {
+        self.secs
+    }
+
+    /// Nanoseconds from `earlier` to `self`, negative if `earlier` is later.
+    #[must_use]
+    pub fn nanos_since(&self, earlier: Timestamp) -> i128 {
+        (i128::from(self.secs) - i128::from(earlier.secs)) * 1_000_000_000 + i128::from(self.nanos)
+            - i128::from(earlier.nanos)
+    }
+
+    /// Formats the timestamp as wall-clock time at `offset`, keeping
+    /// `fraction_digits` digits (at most 9) of the second.
+    #[must_use]
+    pub fn format(&self, offset: UtcOffset, format: TimestampFormat, fraction_digits: usize) -> String {
+        let local = self.secs + i64::from(offset.seconds);
+        let (year, month, day) = civil_from_days(local.div_euclid(86_400));
+        let time = local.rem_euclid(86_400);
+
+        let mut out = String::with_capacity(35);
+        if format != TimestampFormat::Time {
+            let separator = if format == TimestampFormat::Iso8601 { 'T' } else { ' ' };
+            let _ = write!(out, "{year:04}-{month:02}-{day:02}{separator}");
+        }
+        let _ = write!(out, "{:02}:{:02}:{:02}", time / 3_600, time % 3_600 / 60, time % 60);
+
+        let digits = fraction_digits.min(9);
+        if digits > 0 {
+            let fraction = format!("{:09}", self.nanos);
+            out.push('.');
+            out.push_str(&fraction[..digits]);
+        }
+        if format == TimestampFormat::Iso8601 {
+            let _ = write!(out, "{offset}");
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(UtcOffset::UTC, TimestampFormat::Iso8601, 3))
+    }
+}
+
+/// A fixed offset from UTC, e.g. `+02:00`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UtcOffset {
+    seconds: i32,
+}
+
+impl UtcOffset {
+    /// UTC itself.
+    pub const UTC: UtcOffset = UtcOffset { seconds: 0 };
+
+    /// An offset of `seconds` east of UTC, or `None` past ±24 hours.
+    #[must_use]
+    pub const fn from_seconds(seconds: i32) -> Option<Self> {
+        if seconds.abs() < 86_400 {
+            Some(Self { seconds })
+        } else {
+            None
+        }
+    }
+
+    /// Seconds east of UTC.
+    #[must_use]
+    pub const fn seconds(&self) -> i32 {
+        self.seconds
+    }
+
+    /// Parses `+HH:MM`, `+HHMM` or `+HH` (or `-`), as written after a time.
+    fn parse_numeric(s: &str) -> Option<Self> {
+        let (sign, digits) = match s.as_bytes().first()? {
+            b'+' => (1, &s[1..]),
+            b'-' => (-1, &s[1..]),
+            _ => return None,
+        };
+        let digits = digits.replacen(':', "", 1);
+        if !matches!(digits.len(), 2 | 4) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let hours: i32 = digits[..2].parse().ok()?;
+        let minutes: i32 = if digits.len() == 4 {
+            digits[2..].parse().ok()?
+        } else {
+            0
+        };
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+
+        Self::from_seconds(sign * (hours * 3_600 + minutes * 60))
+    }
+}
+
+impl FromStr for UtcOffset {
+    type Err = String;
+
+    /// Accepts `UTC`, `Z` or a numeric offset such as `+02:00`, `-0530` or `+09`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("utc") || s.eq_ignore_ascii_case("z") {
+            return Ok(Self::UTC);
+        }
+
+        Self::parse_numeric(s)
+            .ok_or_else(|| format!("invalid time zone `{s}` (expected UTC or a fixed offset like \"+02:00\")"))
+    }
+}
+
+impl fmt::Display for UtcOffset {
+    /// `Z` for UTC, `+HH:MM` otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.seconds == 0 {
+            return f.write_str("Z");
+        }
+
+        let sign = if self.seconds < 0 { '-' } else { '+' };
+        let minutes = self.seconds.abs() / 60;
+        write!(f, "{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+}
+
+/// How [`Timestamp::format`] writes a timestamp.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// `2024-10-18T12:00:00.123+02:00`
+    #[default]
+    Iso8601,
+    /// `2024-10-18 12:00:00.123`, without the offset.
+    DateTime,
+    /// `12:00:00.123`, without the date or offset.
+    Time,
+}
+
+/// A timestamp located by [`TimestampScanner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampMatch {
+    /// Byte offset of the timestamp's first character.
+    pub start: usize,
+    /// Byte offset just past the timestamp.
+    pub end: usize,
+    /// The instant, reading a timestamp without an offset as UTC.
+    pub timestamp: Timestamp,
+    /// The offset written after the time, if any.
+    pub offset: Option<UtcOffset>,
+    /// Digits written in the fraction of the second.
+    pub fraction_digits: usize,
+}
+
+/// Finds timestamps with both a date and a time: a year-first date as the
+/// date highlighter matches it, `T` or a space, a time as the time
+/// highlighter matches it and an optional `Z` or numeric offset
+/// (`2024-10-18T12:00:00.123+02:00`, `2024-10-18 10:00:00,5 Z`).
+///
+/// Building a scanner compiles a regex; do it once and reuse it.
+#[derive(Debug, Clone)]
+pub struct TimestampScanner {
+    regex: Regex,
+}
+
+impl TimestampScanner {
+    /// Creates a scanner.
+    #[must_use]
+    pub fn new() -> Self {
+        let pattern = format!(
+            r"(?x)
+            \b
+            (?P<date>{YEAR_FIRST_DATE})
+            [T\x20]
+            (?P<time>{CLOCK_TIME})
+            (?P<zone>\x20?(?:Z|[+-]\d{{2}}(?::?\d{{2}})?))?
+            "
+        );
+
+        Self {
+            regex: build_regex(&pattern),
+        }
+    }
+
+    /// All timestamps in `text`, in order. Matches naming an impossible date
+    /// such as `2024-02-30` are skipped.
+    pub fn find_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = TimestampMatch> + 'a {
+        self.regex.captures_iter(text).filter_map(|caps| {
+            let whole = caps.get(0).unwrap();
+            let zone = caps.name("zone").map(|zone| zone.as_str().trim_start());
+            let offset = match zone {
+                Some(zone) => Some(zone.parse::<UtcOffset>().ok()?),
+                None => None,
+            };
+            let (timestamp, fraction_digits) = parse_local(&caps["date"], &caps["time"])?;
+
+            Some(TimestampMatch {
+                start: whole.start(),
+                end: whole.end(),
+                timestamp: timestamp.shifted(-offset.map_or(0, |offset| offset.seconds)),
+                offset,
+                fraction_digits,
+            })
+        })
+    }
+
+    /// The first timestamp in `text`.
+    #[must_use]
+    pub fn find(&self, text: &str) -> Option<TimestampMatch> {
+        self.find_iter(text).next()
+    }
+}
+
+impl Default for TimestampScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timestamp {
+    fn shifted(self, seconds: i32) -> Self {
+        Self {
+            secs: self.secs + i64::from(seconds),
+            nanos: self.nanos,
+        }
+    }
+}
+
+/// Decodes a matched `YYYY-MM-DD` date and `H?H:MM:SS[.fff]` time as if they
+/// were UTC, returning the fraction's digit count alongside.
+fn parse_local(date: &str, time: &str) -> Option<(Timestamp, usize)> {
+    let year: i64 = date[..4].parse().ok()?;
+    let month: u32 = date[5..7].parse().ok()?;
+    let day: u32 = date[8..10].parse().ok()?;
+    if !(1..=12).contains(&month) || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let (clock, fraction) = match time
+        .rfind(['.', ','])
+        .or_else(|| time.match_indices(':').nth(2).map(|(i, _)| i))
+    {
+        Some(split) => (&time[..split], &time[split + 1..]),
+        None => (time, ""),
+    };
+    let mut fields = clock.split(':').map(str::parse::<i64>);
+    let (Some(Ok(hours)), Some(Ok(minutes)), Some(Ok(seconds))) = (fields.next(), fields.next(), fields.next()) else {
+        return None;
+    };
+
+    let nanos = if fraction.is_empty() {
+        0
+    } else {
+        let digits = &fraction[..fraction.len().min(9)];
+        digits.parse::<u32>().ok()? * 10u32.pow(u32::try_from(9 - digits.len()).ok()?)
+    };
+
+    let secs = days_from_civil(year, month, day) * 86_400 + hours * 3_600 + minutes * 60 + seconds;
+    Some((Timestamp { secs, nanos }, fraction.len()))
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts a (year, month, day) in the proleptic Gregorian calendar to days
+/// since 1970-01-01 (Howard Hinnant's `days_from_civil`).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+/// Converts days since 1970-01-01 to a (year, month, day) in the proleptic
+/// Gregorian calendar (Howard Hinnant's `civil_from_days`).
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offset(s: &str) -> UtcOffset {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn formats_iso_8601_utc() {
+        assert_eq!(Timestamp::from_unix_micros(0).to_string(), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            Timestamp::from_unix_micros(1_709_208_000_999_999).to_string(),
+            "2024-02-29T12:00:00.999Z"
+        );
+    }
+
+    #[test]
+    fn nanos_truncate_to_millis() {
+        let timestamp = Timestamp::from_unix_nanos(1_729_245_600_123_456_789).unwrap();
+        assert_eq!(timestamp.to_string(), "2024-10-18T10:00:00.123Z");
+        assert_eq!(timestamp.unix_secs(), 1_729_245_600);
+    }
+
+    #[test]
+    fn parses_offsets() {
+        assert_eq!(offset("UTC"), UtcOffset::UTC);
+        assert_eq!(offset("z"), UtcOffset::UTC);
+        assert_eq!(offset("+02:00").seconds(), 7_200);
+        assert_eq!(offset("-0530").seconds(), -19_800);
+        assert_eq!(offset("+09").seconds(), 32_400);
+        assert_eq!(offset("-05:30").to_string(), "-05:30");
+        assert!("Europe/Berlin".parse::<UtcOffset>().is_err());
+        assert!("+24:00".parse::<UtcOffset>().is_err());
+    }
+
+    #[test]
+    fn scans_timestamps_with_and_without_offsets() {
+        let scanner = TimestampScanner::new();
+        let text = "a 2024-10-18T12:00:00.123+02:00 b 2024-10-18 10:00:00,5 Z c 2024-10-18 10:00:00 d";
+        let found: Vec<_> = scanner.find_iter(text).collect();
+
+        assert_eq!(found.len(), 3);
+        assert_eq!(&text[found[0].start..found[0].end], "2024-10-18T12:00:00.123+02:00");
+        assert_eq!(found[0].timestamp.to_string(), "2024-10-18T10:00:00.123Z");
+        assert_eq!(found[0].fraction_digits, 3);
+        assert_eq!(&text[found[1].start..found[1].end], "2024-10-18 10:00:00,5 Z");
+        assert_eq!(found[1].offset, Some(UtcOffset::UTC));
+        assert_eq!(found[2].offset, None);
+        assert_eq!(found[2].timestamp.to_string(), "2024-10-18T10:00:00.000Z");
+    }
+
+    #[test]
+    fn skips_impossible_dates_and_bare_times() {
+        let scanner = TimestampScanner::new();
+        assert_eq!(scanner.find("2023-02-29T10:00:00Z"), None);
+        assert_eq!(scanner.find("at 10:00:00Z"), None);
+        assert!(scanner.find("2024-02-29T10:00:00Z").is_some());
+    }
+
+    #[test]
+    fn formats_in_an_offset() {
+        let found = TimestampScanner::new().find("2024-10-18T23:30:00.123456Z").unwrap();
+        let plus_two = offset("+02:00");
+
+        assert_eq!(
+            found.timestamp.format(plus_two, TimestampFormat::Iso8601, 6),
+            "2024-10-19T01:30:00.123456+02:00"
+        );
+        assert_eq!(
+            found.timestamp.format(plus_two, TimestampFormat::DateTime, 3),
+            "2024-10-19 01:30:00.123"
+        );
+        assert_eq!(found.timestamp.format(plus_two, TimestampFormat::Time, 0), "01:30:00");
+    }
+
+    #[test]
+    fn measures_the_gap_between_timestamps() {
+        let scanner = TimestampScanner::new();
+        let first = scanner.find("2024-10-18T23:59:59.900Z").unwrap().timestamp;
+        let second = scanner.find("2024-10-19T00:00:00.053Z").unwrap().timestamp;
+
+        assert_eq!(second.nanos_since(first), 153_000_000);
+        assert_eq!(first.nanos_since(second), -153_000_000);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::io::reader::{LineBatch, Reader, StreamEvent};
+use anyhow::Result;
+use memchr::memchr;
+
+/// Pads the container prefixes of multiplexed logs (`[pod/name/container]`
+/// from `kubectl logs --prefix`, `name  | ` from `docker compose logs`) so
+/// every message starts in the same column. The width only grows: a new,
+/// longer container shifts later lines, never earlier ones.
+pub struct AlignReader {
+    inner: Box<Reader>,
+    width: usize,
+}
+
+impl AlignReader {
+    pub fn new(inner: Reader) -> AlignReader {
+        AlignReader {
+            inner: Box::new(inner),
+            width: 0,
+        }
+    }
+
+    pub fn inner(&self) -> &Reader {
+        &self.inner
+    }
+
+    pub fn next(&mut self) -> Result<StreamEvent> {
+        let batch = match self.inner.next()? {
+            StreamEvent::Lines(batch) => batch,
+            event => return Ok(event),
+        };
+
+        let mut aligned = LineBatch::default();
+        for range in &batch.lines {
+            let line = &batch.buf[range.clone()];
+            match split_prefix(line) {
+                Some(prefix) => aligned.push(&prefix.aligned(&mut self.width)),
+                None => aligned.push(line),
+            }
+        }
+
+        Ok(StreamEvent::Lines(aligned))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Prefix<'a> {
+    label: &'a [u8],
+    delimiter: &'static [u8],
+    message: &'a [u8],
+}
+
+impl Prefix<'_> {
+    /// The line with the label padded to `width`, widened first if needed.
+    fn aligned(&self, width: &mut usize) -> Vec<u8> {
+        *width = (*width).max(self.label.len());
+
+        let mut line = Vec::with_capacity(*width + self.delimiter.len() + self.message.len());
+        line.extend_from_slice(self.label);
+        line.resize(*width, b' ');
+        line.extend_from_slice(self.delimiter);
+        line.extend_from_slice(self.message);
+
+        line
+    }
+}
+
+/// Splits a container prefix off `line`, dropping the padding after the label.
+fn split_prefix(line: &[u8]) -> Option<Prefix<'_>> {
+    if line.starts_with(b"[pod/") {
+        let close = memchr(b']', line)?;
+        let message = line[close + 1..].strip_prefix(b" ")?;
+
+        return Some(Prefix {
+            label: &line[..=close],
+            delimiter: b" ",
+            message,
+        });
+    }
+
+    let bar = memchr(b'|', line)?;
+    let label = line[..bar].trim_ascii_end();
+    let is_name = label.first().is_some_and(u8::is_ascii_alphanumeric)
+        && label
+            .iter()
+            .all(|&b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'.' | b'-'));
+    // Compose always pads the name with at least one space.
+    if !is_name || label.len() == bar {
+        return None;
+    }
+
+    Some(Prefix {
+        label,
+        delimiter: b" |",
+        message: &line[bar + 1..],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn align_all(lines: &[&str]) -> Vec<String> {
+        let mut width = 0;
+        lines
+            .iter()
+            .map(|line| match split_prefix(line.as_bytes()) {
+                Some(prefix) => String::from_utf8(prefix.aligned(&mut width)).unwrap(),
+                None => (*line).to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn kubectl_prefixes_align_to_the_widest_seen() {
+        assert_eq!(
+            align_all(&["[pod/web-0/app] a", "[pod/worker-12/app] b", "[pod/web-0/app] c"]),
+            ["[pod/web-0/app] a", "[pod/worker-12/app] b", "[pod/web-0/app]     c"]
+        );
+    }
+
+    #[test]
+    fn compose_prefixes_are_repadded() {
+        assert_eq!(
+            align_all(&["db-1 | ready", "api-1  |   indented", "db-1 |"]),
+            ["db-1 | ready", "api-1 |   indented", "db-1  |"]
+        );
+    }
+
+    #[test]
+    fn other_lines_are_left_alone() {
+        assert_eq!(split_prefix(b"grep foo | wc -l"), None);
+        assert_eq!(split_prefix(b"a|b"), None);
+        assert_eq!(split_prefix(b"[pod/unclosed message"), None);
+        assert_eq!(split_prefix(b"plain line"), None);
+    }
+}
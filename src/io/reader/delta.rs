@@ -0,0 +1,123 @@
+use crate::io::reader::{LineBatch, Reader, StreamEvent};
+use anyhow::Result;
+use tailspin::time::{Timestamp, TimestampScanner};
+
+/// Width of a delta, sign and unit included (`   +0.153s`, `+999.999s`).
+const WIDTH: usize = 9;
+
+/// Width of the column before each line: a delta, or blanks, and a space.
+/// Fixed, so the highlighter can skip it without parsing it.
+pub const COLUMN: usize = WIDTH + 1;
+
+/// Prefixes every line with the time since the previous timestamped line
+/// (`+0.153s`), taken from the first full date and time on each line. Lines
+/// without one get a blank column so messages stay aligned.
+pub struct DeltaReader {
+    inner: Box<Reader>,
+    deltas: Deltas,
+}
+
+impl DeltaReader {
+    pub fn new(inner: Reader) -> DeltaReader {
+        DeltaReader {
+            inner: Box::new(inner),
+            deltas: Deltas {
+                scanner: TimestampScanner::new(),
+                previous: None,
+            },
+        }
+    }
+
+    pub fn inner(&self) -> &Reader {
+        &self.inner
+    }
+
+    pub fn next(&mut self) -> Result<StreamEvent> {
+        let batch = match self.inner.next()? {
+            StreamEvent::Lines(batch) => batch,
+            event => return Ok(event),
+        };
+
+        let mut prefixed = LineBatch::default();
+        for range in &batch.lines {
+            let line = &batch.buf[range.clone()];
+            let mut out = self.deltas.prefix(&String::from_utf8_lossy(line)).into_bytes();
+            out.extend_from_slice(line);
+            prefixed.push(&out);
+        }
+
+        Ok(StreamEvent::Lines(prefixed))
+    }
+}
+
+struct Deltas {
+    scanner: TimestampScanner,
+    previous: Option<Timestamp>,
+}
+
+impl Deltas {
+    /// The column to put before `line`, remembering its timestamp for the next.
+    fn prefix(&mut self, line: &str) -> String {
+        let Some(found) = self.scanner.find(line) else {
+            return " ".repeat(COLUMN);
+        };
+
+        let nanos = self
+            .previous
+            .replace(found.timestamp)
+            .map_or(0, |previous| found.timestamp.nanos_since(previous));
+        let sign = if nanos < 0 { '-' } else { '+' };
+        let millis = nanos.unsigned_abs() / 1_000_000;
+        let seconds = millis / 1000;
+        // Gaps too long for the column in seconds fall back to whole minutes,
+        // then whole hours.
+        let delta = if seconds < 1000 {
+            format!("{sign}{seconds}.{:03}s", millis % 1000)
+        } else if seconds < 1000 * 60 {
+            format!("{sign}{}m", seconds / 60)
+        } else {
+            format!("{sign}{}h", (seconds / 3600).min(9_999_999))
+        };
+
+        format!("{delta:>WIDTH$} ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deltas() -> Deltas {
+        Deltas {
+            scanner: TimestampScanner::new(),
+            previous: None,
+        }
+    }
+
+    #[test]
+    fn measures_from_the_previous_timestamp() {
+        let mut deltas = deltas();
+        assert_eq!(deltas.prefix("2024-10-18 22:30:00.000 INFO start"), "  +0.000s ");
+        assert_eq!(deltas.prefix("2024-10-18 22:30:00.153 INFO ready"), "  +0.153s ");
+        assert_eq!(deltas.prefix("2024-10-18T22:30:12.653Z WARN slow"), " +12.500s ");
+        assert_eq!(deltas.prefix("2024-10-18 22:30:10 INFO clock moved"), "  -2.653s ");
+    }
+
+    #[test]
+    fn lines_without_a_timestamp_get_a_blank_column() {
+        let mut deltas = deltas();
+        assert_eq!(deltas.prefix("2024-10-18 22:30:00 INFO start"), "  +0.000s ");
+        assert_eq!(deltas.prefix("    at Main.run(Main.java:12)"), "          ");
+        assert_eq!(deltas.prefix("2024-10-18 22:30:01 INFO next"), "  +1.000s ");
+    }
+
+    #[test]
+    fn long_gaps_keep_the_column_width() {
+        let mut deltas = deltas();
+        assert_eq!(deltas.prefix("2024-10-18 22:00:00 a"), "  +0.000s ");
+        assert_eq!(deltas.prefix("2024-10-18 22:16:39.999 b"), "+999.999s ");
+        assert_eq!(deltas.prefix("2024-10-18 23:00:00 c"), "     +43m ");
+        assert_eq!(deltas.prefix("2024-10-28 23:00:00 d"), "    +240h ");
+        assert_eq!(deltas.prefix("1970-01-01 00:00:00 e"), " -480599h ");
+    }
+}
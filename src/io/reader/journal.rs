@@ -0,0 +1,408 @@
+use crate::io::reader::{LineBatch, Reader, StreamEvent};
+use anyhow::Result;
+use serde_json::{Map, Value};
+use std::fmt::Write;
+use std::ops::Range;
+use tailspin::time::Timestamp;
+
+/// Renders systemd journal entries read by another reader, either one
+/// `journalctl -o json` object per line or the multi-line export format,
+/// as `timestamp unit[pid]: LEVEL message`. Lines that are neither pass
+/// through untouched.
+pub struct JournalReader {
+    inner: Box<Reader>,
+    renderer: Renderer,
+    ended: bool,
+}
+
+impl JournalReader {
+    pub fn new(inner: Reader, units: Vec<String>) -> JournalReader {
+        JournalReader {
+            inner: Box::new(inner),
+            renderer: Renderer::new(units),
+            ended: false,
+        }
+    }
+
+    pub fn inner(&self) -> &Reader {
+        &self.inner
+    }
+
+    pub fn next(&mut self) -> Result<StreamEvent> {
+        if self.ended {
+            return Ok(StreamEvent::Ended);
+        }
+
+        loop {
+            let mut out = LineBatch::default();
+
+            match self.inner.next()? {
+                StreamEvent::Lines(batch) => {
+                    for range in &batch.lines {
+                        let line = if self.renderer.in_binary_field() {
+                            raw_line(&batch, range)
+                        } else {
+                            &batch.buf[range.clone()]
+                        };
+                        self.renderer.push(line, &mut out);
+                    }
+                }
+                StreamEvent::Ended => {
+                    self.ended = true;
+                    self.renderer.finish(&mut out);
+                    if out.lines.is_empty() {
+                        return Ok(StreamEvent::Ended);
+                    }
+                }
+                event @ StreamEvent::InitialReadComplete => return Ok(event),
+            }
+
+            // A batch of filtered-out entries renders nothing; keep reading
+            if !out.lines.is_empty() {
+                return Ok(StreamEvent::Lines(out));
+            }
+        }
+    }
+}
+
+/// The line with the `\r` that [`LineBatch`] strips put back, when the buffer
+/// still holds it: binary export fields count every byte.
+fn raw_line<'a>(batch: &'a LineBatch, range: &Range<usize>) -> &'a [u8] {
+    let end = range.end + usize::from(batch.buf.get(range.end) == Some(&b'\r'));
+
+    &batch.buf[range.start..end]
+}
+
+/// The fields an entry is rendered from. Repeated fields keep their first value.
+#[derive(Debug, Default)]
+struct Entry {
+    timestamp: Option<String>,
+    unit: Option<String>,
+    about_unit: Option<String>,
+    identifier: Option<String>,
+    pid: Option<String>,
+    priority: Option<String>,
+    message: Option<String>,
+}
+
+impl Entry {
+    fn from_json(fields: &Map<String, Value>) -> Entry {
+        let mut entry = Entry::default();
+        for (key, value) in fields {
+            if let Some(text) = json_text(value) {
+                entry.set(key, text);
+            }
+        }
+
+        entry
+    }
+
+    fn set(&mut self, key: &str, value: String) {
+        let slot = match key {
+            "__REALTIME_TIMESTAMP" => &mut self.timestamp,
+            "_SYSTEMD_UNIT" => &mut self.unit,
+            // systemd's own messages about a unit, e.g. "Started nginx.service"
+            "UNIT" => &mut self.about_unit,
+            "SYSLOG_IDENTIFIER" => &mut self.identifier,
+            "_PID" => &mut self.pid,
+            "PRIORITY" => &mut self.priority,
+            "MESSAGE" => &mut self.message,
+            _ => return,
+        };
+        slot.get_or_insert(value);
+    }
+
+    fn render(&self, out: &mut LineBatch) {
+        let mut header = String::new();
+
+        if let Some(usec) = self.timestamp.as_deref().and_then(|t| t.parse().ok()) {
+            let _ = write!(header, "{}", Timestamp::from_unix_micros(usec));
+            header.push(' ');
+        }
+
+        if let Some(name) = self.unit.as_ref().or(self.identifier.as_ref()) {
+            header.push_str(name);
+            if let Some(pid) = &self.pid {
+                let _ = write!(header, "[{pid}]");
+            }
+            header.push_str(": ");
+        }
+
+        if let Some(level) = self.priority.as_deref().and_then(level_keyword) {
+            header.push_str(level);
+            header.push(' ');
+        }
+
+        // Continuation lines go out bare so finders anchored at the start of
+        // a line, like the JVM stack trace one, still see them.
+        let mut lines = self.message.as_deref().unwrap_or_default().split('\n');
+        let first = lines.next().unwrap_or_default();
+        out.push(format!("{header}{first}").trim_end().as_bytes());
+        for line in lines {
+            out.push(line.as_bytes());
+        }
+    }
+}
+
+/// Maps a syslog `PRIORITY` onto the level keywords the default theme styles.
+fn level_keyword(priority: &str) -> Option<&'static str> {
+    match priority {
+        "0" | "1" | "2" | "3" => Some("ERROR"),
+        "4" => Some("WARN"),
+        "5" | "6" => Some("INFO"),
+        "7" => Some("DEBUG"),
+        _ => None,
+    }
+}
+
+/// Journal JSON encodes non-UTF-8 values as byte arrays and repeated fields as
+/// arrays of values.
+fn json_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Array(items) => {
+            let bytes: Option<Vec<u8>> = items
+                .iter()
+                .map(|item| item.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+                .collect();
+
+            match bytes {
+                Some(bytes) => Some(String::from_utf8_lossy(&bytes).into_owned()),
+                None => items.first().and_then(json_text),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Journal field names: uppercase letters, digits and underscores, not
+/// starting with a digit.
+fn is_field_name(name: &[u8]) -> bool {
+    name.first().is_some_and(|b| !b.is_ascii_digit())
+        && name
+            .iter()
+            .all(|&b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_')
+}
+
+/// A binary export field: the name on its own line, then a little-endian
+/// u64 length and that many bytes, which may span several lines.
+#[derive(Debug)]
+struct BinaryField {
+    key: String,
+    data: Option<Vec<u8>>,
+}
+
+impl BinaryField {
+    fn value(data: &[u8]) -> Option<&[u8]> {
+        let len = u64::from_le_bytes(data.get(..8)?.try_into().ok()?);
+        let end = usize::try_from(len).ok()?.checked_add(8)?;
+
+        data.get(8..end)
+    }
+}
+
+/// Turns journal lines into rendered lines, carrying a partially read
+/// export-format entry across batches.
+#[derive(Debug)]
+struct Renderer {
+    units: Vec<String>,
+    pending: Option<Entry>,
+    binary: Option<BinaryField>,
+}
+
+impl Renderer {
+    fn new(units: Vec<String>) -> Renderer {
+        Renderer {
+            units,
+            pending: None,
+            binary: None,
+        }
+    }
+
+    fn in_binary_field(&self) -> bool {
+        self.binary.is_some()
+    }
+
+    fn push(&mut self, line: &[u8], out: &mut LineBatch) {
+        if let Some(mut field) = self.binary.take() {
+            let data = match field.data.take() {
+                None => line.to_vec(),
+                Some(mut data) => {
+                    data.push(b'\n');
+                    data.extend_from_slice(line);
+                    data
+                }
+            };
+
+            if let Some(value) = BinaryField::value(&data) {
+                let value = String::from_utf8_lossy(value).into_owned();
+                self.pending.get_or_insert_default().set(&field.key, value);
+            } else {
+                field.data = Some(data);
+                self.binary = Some(field);
+            }
+            return;
+        }
+
+        if line.is_empty() {
+            match self.pending.take() {
+                Some(entry) => self.render(&entry, out),
+                None => out.push(line),
+            }
+            return;
+        }
+
+        if line.trim_ascii_start().starts_with(b"{") {
+            match serde_json::from_slice::<Map<String, Value>>(line) {
+                Ok(fields) => self.render(&Entry::from_json(&fields), out),
+                Err(_) => out.push(line),
+            }
+            return;
+        }
+
+        if let Some(eq) = line.iter().position(|&b| b == b'=')
+            && is_field_name(&line[..eq])
+        {
+            let key = String::from_utf8_lossy(&line[..eq]);
+            let value = String::from_utf8_lossy(&line[eq + 1..]).into_owned();
+            self.pending.get_or_insert_default().set(&key, value);
+            return;
+        }
+
+        // A bare field name only starts a binary field inside an entry, so a
+        // stray all-caps line cannot swallow the lines after it.
+        if self.pending.is_some() && is_field_name(line) {
+            self.binary = Some(BinaryField {
+                key: String::from_utf8_lossy(line).into_owned(),
+                data: None,
+            });
+            return;
+        }
+
+        out.push(line);
+    }
+
+    /// Renders an export entry left open at the end of the stream.
+    fn finish(&mut self, out: &mut LineBatch) {
+        self.binary = None;
+        if let Some(entry) = self.pending.take() {
+            self.render(&entry, out);
+        }
+    }
+
+    fn render(&self, entry: &Entry, out: &mut LineBatch) {
+        if self.matches_units(entry) {
+            entry.render(out);
+        }
+    }
+
+    fn matches_units(&self, entry: &Entry) -> bool {
+        self.units.is_empty()
+            || [&entry.unit, &entry.about_unit]
+                .into_iter()
+                .flatten()
+                .any(|unit| self.units.iter().any(|filter| unit_matches(filter, unit)))
+    }
+}
+
+/// Like `journalctl --unit`, a name without a type suffix means the service.
+fn unit_matches(filter: &str, unit: &str) -> bool {
+    unit == filter || (!filter.contains('.') && unit.strip_suffix(".service") == Some(filter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    fn render(units: &[&str], input: &[u8]) -> Vec<String> {
+        let mut renderer = Renderer::new(units.iter().map(ToString::to_string).collect());
+        let mut out = LineBatch::default();
+        for line in input.split(|&b| b == b'\n') {
+            renderer.push(line, &mut out);
+        }
+        renderer.finish(&mut out);
+
+        out.iter().map(Cow::into_owned).collect()
+    }
+
+    const NGINX_JSON: &str = r#"{"__REALTIME_TIMESTAMP":"1792332131003512","_SYSTEMD_UNIT":"nginx.service","SYSLOG_IDENTIFIER":"nginx","_PID":"4242","PRIORITY":"3","MESSAGE":"upstream timed out"}"#;
+
+    #[test]
+    fn json_entry_renders_unit_pid_and_level() {
+        assert_eq!(
+            render(&[], NGINX_JSON.as_bytes()),
+            ["2026-10-18T14:02:11.003Z nginx.service[4242]: ERROR upstream timed out"]
+        );
+    }
+
+    #[test]
+    fn priorities_map_to_level_keywords() {
+        for (priority, level) in [
+            ("0", "ERROR"),
+            ("4", "WARN"),
+            ("5", "INFO"),
+            ("6", "INFO"),
+            ("7", "DEBUG"),
+        ] {
+            let line = format!(r#"{{"SYSLOG_IDENTIFIER":"kernel","PRIORITY":"{priority}","MESSAGE":"hi"}}"#);
+            assert_eq!(render(&[], line.as_bytes()), [format!("kernel: {level} hi")]);
+        }
+    }
+
+    #[test]
+    fn json_byte_arrays_and_repeated_fields_decode() {
+        let line = r#"{"SYSLOG_IDENTIFIER":["app","app-alias"],"MESSAGE":[104,105,255]}"#;
+        assert_eq!(render(&[], line.as_bytes()), ["app: hi\u{FFFD}"]);
+    }
+
+    #[test]
+    fn multi_line_messages_keep_continuation_lines_bare() {
+        let line =
+            r#"{"SYSLOG_IDENTIFIER":"java","MESSAGE":"java.io.IOException: closed\n\tat Main.run(Main.java:7)"}"#;
+        assert_eq!(
+            render(&[], line.as_bytes()),
+            ["java: java.io.IOException: closed", "\tat Main.run(Main.java:7)"]
+        );
+    }
+
+    #[test]
+    fn export_entries_end_at_blank_lines() {
+        let input = b"__CURSOR=s=1\n__REALTIME_TIMESTAMP=1792332131003512\n_SYSTEMD_UNIT=sshd.service\n_PID=7\nPRIORITY=6\nMESSAGE=Accepted publickey\n\nSYSLOG_IDENTIFIER=kernel\nMESSAGE=eth0 up";
+        assert_eq!(
+            render(&[], input),
+            [
+                "2026-10-18T14:02:11.003Z sshd.service[7]: INFO Accepted publickey",
+                "kernel: eth0 up"
+            ]
+        );
+    }
+
+    #[test]
+    fn export_binary_fields_may_span_lines() {
+        let mut input = b"SYSLOG_IDENTIFIER=app\nMESSAGE\n".to_vec();
+        input.extend_from_slice(&10u64.to_le_bytes());
+        input.extend_from_slice(b"two\r\nlines\n");
+        assert_eq!(render(&[], &input), ["app: two", "lines"]);
+    }
+
+    #[test]
+    fn unit_filter_matches_with_or_without_service_suffix() {
+        let about = r#"{"SYSLOG_IDENTIFIER":"systemd","UNIT":"nginx.service","MESSAGE":"Started nginx"}"#;
+        let other = r#"{"_SYSTEMD_UNIT":"cron.service","MESSAGE":"tick"}"#;
+        let input = [NGINX_JSON, about, other].join("\n");
+
+        assert_eq!(render(&["nginx"], input.as_bytes()).len(), 2);
+        assert_eq!(render(&["nginx.service", "cron.service"], input.as_bytes()).len(), 3);
+        assert!(render(&["nginx.socket"], input.as_bytes()).is_empty());
+    }
+
+    #[test]
+    fn other_lines_pass_through() {
+        assert_eq!(
+            render(&[], b"-- No entries --\n{not json\nERROR"),
+            ["-- No entries --", "{not json", "ERROR"]
+        );
+    }
+}
@@ -9,7 +9,7 @@ pub const BUF_READER_CAPACITY: usize = 1024 * 1024;
 
 /// A batch of complete lines sharing one buffer. `lines` holds each line's
 /// byte range within `buf`, with line endings (`\n`, `\r\n`) stripped.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct LineBatch {
     pub buf: Vec<u8>,
     pub lines: Vec<Range<usize>>,
@@ -17,7 +17,7 @@ pub struct LineBatch {
 
 impl LineBatch {
     /// Splits a chunk with no trailing newline into a batch of lines.
-    fn from_chunk(chunk: &[u8]) -> Self {
+    pub fn from_chunk(chunk: &[u8]) -> Self {
         let buf = chunk.to_vec();
         let mut lines = Vec::new();
         let mut start = 0;
@@ -41,6 +41,13 @@ impl LineBatch {
         }
     }
 
+    /// Appends a line, which must not contain a line ending.
+    pub fn push(&mut self, line: &[u8]) {
+        let start = self.buf.len();
+        self.buf.extend_from_slice(line);
+        self.lines.push(start..self.buf.len());
+    }
+
     /// The lines as lossily-decoded text, borrowing from the batch buffer
     /// unless a line contains invalid UTF-8.
     #[cfg(test)]
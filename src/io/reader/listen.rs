@@ -0,0 +1,158 @@
+use crate::io::reader::StreamEvent;
+use crate::io::reader::line_batcher::{BUF_READER_CAPACITY, LineBatch, ReadResult, read_batch};
+use crate::io::routing::ListenAddr;
+use anyhow::{Context, Result};
+use std::io::{self, BufReader};
+use std::net::{TcpListener, TcpStream, UdpSocket};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+/// Largest possible UDP payload; syslog datagrams are far smaller.
+const MAX_DATAGRAM_SIZE: usize = 65_535;
+
+/// Receives lines from UDP datagrams or newline-framed TCP connections.
+/// Socket I/O runs on background threads that feed a channel, so `next`
+/// only ever blocks on the channel.
+pub struct ListenReader {
+    batches: Receiver<io::Result<LineBatch>>,
+    initial_read_complete_sent: bool,
+}
+
+impl ListenReader {
+    /// Binds up front so an address in use fails before the pager opens.
+    pub fn new(addr: &ListenAddr) -> Result<ListenReader> {
+        let context = || format!("Could not listen on {addr}");
+
+        match addr {
+            ListenAddr::Udp(host) => Ok(Self::udp(UdpSocket::bind(host).with_context(context)?)),
+            ListenAddr::Tcp(host) => Ok(Self::tcp(TcpListener::bind(host).with_context(context)?)),
+        }
+    }
+
+    /// Each datagram is one batch; a trailing newline is optional.
+    fn udp(socket: UdpSocket) -> ListenReader {
+        let (tx, batches) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut buf = vec![0; MAX_DATAGRAM_SIZE];
+            loop {
+                let result = socket.recv(&mut buf).map(|len| datagram_batch(&buf[..len]));
+                let failed = result.is_err();
+                if tx.send(result).is_err() || failed {
+                    return;
+                }
+            }
+        });
+
+        Self::from_channel(batches)
+    }
+
+    /// Serves every connection on its own thread. A dropped connection only
+    /// ends that connection, never the stream.
+    fn tcp(listener: TcpListener) -> ListenReader {
+        let (tx, batches) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let tx = tx.clone();
+                thread::spawn(move || read_connection(stream, &tx));
+            }
+        });
+
+        Self::from_channel(batches)
+    }
+
+    fn from_channel(batches: Receiver<io::Result<LineBatch>>) -> ListenReader {
+        ListenReader {
+            batches,
+            initial_read_complete_sent: false,
+        }
+    }
+
+    pub fn next(&mut self) -> Result<StreamEvent> {
+        if !self.initial_read_complete_sent {
+            self.initial_read_complete_sent = true;
+
+            return Ok(StreamEvent::InitialReadComplete);
+        }
+
+        match self.batches.recv() {
+            Ok(batch) => Ok(StreamEvent::Lines(batch.context("Could not receive from socket")?)),
+            Err(_) => Ok(StreamEvent::Ended),
+        }
+    }
+}
+
+fn datagram_batch(datagram: &[u8]) -> LineBatch {
+    let datagram = datagram.strip_suffix(b"\n").unwrap_or(datagram);
+
+    LineBatch::from_chunk(datagram)
+}
+
+fn read_connection(stream: TcpStream, tx: &Sender<io::Result<LineBatch>>) {
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY, stream);
+
+    while let Ok(ReadResult::Batch(batch)) = read_batch(&mut reader) {
+        if tx.send(Ok(batch)).is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::io::Write;
+
+    fn next_lines(reader: &mut ListenReader) -> Vec<String> {
+        match reader.next().unwrap() {
+            StreamEvent::Lines(batch) => batch.iter().map(Cow::into_owned).collect(),
+            other => panic!("Expected StreamEvent::Lines, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn udp_datagrams_become_batches() {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = socket.local_addr().unwrap();
+        let mut reader = ListenReader::udp(socket);
+
+        assert!(matches!(reader.next().unwrap(), StreamEvent::InitialReadComplete));
+
+        let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+        sender.send_to(b"<34>Oct 11 22:14:15 host su: failed", addr).unwrap();
+        assert_eq!(next_lines(&mut reader), ["<34>Oct 11 22:14:15 host su: failed"]);
+
+        sender.send_to(b"first\r\nsecond\n", addr).unwrap();
+        assert_eq!(next_lines(&mut reader), ["first", "second"]);
+    }
+
+    #[test]
+    fn tcp_connections_are_split_into_lines() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut reader = ListenReader::tcp(listener);
+
+        assert!(matches!(reader.next().unwrap(), StreamEvent::InitialReadComplete));
+
+        let mut first = TcpStream::connect(addr).unwrap();
+        first.write_all(b"from first\n").unwrap();
+        assert_eq!(next_lines(&mut reader), ["from first"]);
+        drop(first);
+
+        // A closed connection leaves the listener serving new ones
+        let mut second = TcpStream::connect(addr).unwrap();
+        second.write_all(b"from second\n").unwrap();
+        assert_eq!(next_lines(&mut reader), ["from second"]);
+    }
+
+    #[test]
+    fn address_in_use_fails_on_construction() {
+        let taken = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = ListenAddr::Tcp(taken.local_addr().unwrap().to_string());
+
+        let err = ListenReader::new(&addr).err().unwrap();
+        assert!(err.to_string().starts_with("Could not listen on tcp://127.0.0.1:"));
+    }
+}
@@ -1,11 +1,21 @@
+pub mod align;
 pub mod command;
+pub mod delta;
 pub mod file_reader;
+pub mod journal;
 mod line_batcher;
+pub mod listen;
 pub mod stdin;
+pub mod time_zone;
 
+use crate::io::reader::align::AlignReader;
 use crate::io::reader::command::CommandReader;
+use crate::io::reader::delta::DeltaReader;
 use crate::io::reader::file_reader::FileReader;
+use crate::io::reader::journal::JournalReader;
+use crate::io::reader::listen::ListenReader;
 use crate::io::reader::stdin::StdinReader;
+use crate::io::reader::time_zone::TimeZoneReader;
 use anyhow::Result;
 use shared_child::SharedChild;
 use std::sync::Arc;
@@ -16,16 +26,22 @@ pub enum Reader {
     File(FileReader),
     Stdin(StdinReader),
     Command(CommandReader),
+    Listen(ListenReader),
+    Journal(JournalReader),
+    Align(AlignReader),
+    TimeZone(TimeZoneReader),
+    Delta(DeltaReader),
 }
 
 /// Events produced by [`Reader::next`].
 #[derive(Debug)]
 pub enum StreamEvent {
     /// Emitted exactly once, always before `Ended`. File readers send it
-    /// after draining the content that existed at startup; stdin and command
-    /// readers send it immediately. The pager path spawns its pager on this
-    /// event, so a file's existing content is fully written to the temp file
-    /// before the pager opens, while `--exec` output streams in live.
+    /// after draining the content that existed at startup; stdin, command
+    /// and listen readers send it immediately. The pager path spawns its
+    /// pager on this event, so a file's existing content is fully written to
+    /// the temp file before the pager opens, while `--exec` and `--listen`
+    /// output streams in live.
     InitialReadComplete,
 
     /// The stream is exhausted; no more events follow.
@@ -41,6 +57,11 @@ impl Reader {
             Reader::File(r) => r.next(),
             Reader::Stdin(r) => r.next(),
             Reader::Command(r) => r.next(),
+            Reader::Listen(r) => r.next(),
+            Reader::Journal(r) => r.next(),
+            Reader::Align(r) => r.next(),
+            Reader::TimeZone(r) => r.next(),
+            Reader::Delta(r) => r.next(),
         }
     }
 
@@ -48,7 +69,11 @@ impl Reader {
     pub fn exec_child(&self) -> Option<Arc<SharedChild>> {
         match self {
             Reader::Command(r) => Some(r.child()),
-            Reader::File(_) | Reader::Stdin(_) => None,
+            Reader::Journal(r) => r.inner().exec_child(),
+            Reader::Align(r) => r.inner().exec_child(),
+            Reader::TimeZone(r) => r.inner().exec_child(),
+            Reader::Delta(r) => r.inner().exec_child(),
+            Reader::File(_) | Reader::Stdin(_) | Reader::Listen(_) => None,
         }
     }
 }
@@ -0,0 +1,138 @@
+use crate::io::reader::{LineBatch, Reader, StreamEvent};
+use anyhow::Result;
+use tailspin::time::{TimestampFormat, TimestampScanner, UtcOffset};
+
+/// Rewrites every timestamp that carries a zone (`Z`, `+02:00`) into one
+/// fixed offset and format, so logs from hosts in different zones line up.
+/// Timestamps without a zone are left alone: their zone is unknown.
+pub struct TimeZoneReader {
+    inner: Box<Reader>,
+    rewriter: Rewriter,
+}
+
+impl TimeZoneReader {
+    pub fn new(inner: Reader, offset: UtcOffset, format: TimestampFormat) -> TimeZoneReader {
+        TimeZoneReader {
+            inner: Box::new(inner),
+            rewriter: Rewriter {
+                scanner: TimestampScanner::new(),
+                offset,
+                format,
+            },
+        }
+    }
+
+    pub fn inner(&self) -> &Reader {
+        &self.inner
+    }
+
+    pub fn next(&mut self) -> Result<StreamEvent> {
+        let batch = match self.inner.next()? {
+            StreamEvent::Lines(batch) => batch,
+            event => return Ok(event),
+        };
+
+        let mut rewritten = LineBatch::default();
+        for range in &batch.lines {
+            let line = &batch.buf[range.clone()];
+            match self.rewriter.rewrite(line) {
+                Some(line) => rewritten.push(&line),
+                None => rewritten.push(line),
+            }
+        }
+
+        Ok(StreamEvent::Lines(rewritten))
+    }
+}
+
+struct Rewriter {
+    scanner: TimestampScanner,
+    offset: UtcOffset,
+    format: TimestampFormat,
+}
+
+impl Rewriter {
+    /// The line with its zoned timestamps rewritten, or `None` if it has none.
+    /// Timestamps are looked for in the valid UTF-8 stretches of the line; any
+    /// other bytes are copied as they are.
+    fn rewrite(&self, line: &[u8]) -> Option<Vec<u8>> {
+        let mut out = Vec::new();
+        let mut rewritten = false;
+
+        for chunk in line.utf8_chunks() {
+            let valid = chunk.valid();
+            let mut pos = 0;
+            for found in self.scanner.find_iter(valid).filter(|found| found.offset.is_some()) {
+                out.extend_from_slice(&valid.as_bytes()[pos..found.start]);
+                let timestamp = found.timestamp.format(self.offset, self.format, found.fraction_digits);
+                out.extend_from_slice(timestamp.as_bytes());
+                pos = found.end;
+                rewritten = true;
+            }
+            out.extend_from_slice(&valid.as_bytes()[pos..]);
+            out.extend_from_slice(chunk.invalid());
+        }
+
+        rewritten.then_some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewriter(offset: &str, format: TimestampFormat) -> Rewriter {
+        Rewriter {
+            scanner: TimestampScanner::new(),
+            offset: offset.parse().unwrap(),
+            format,
+        }
+    }
+
+    fn rewrite(rewriter: &Rewriter, line: &str) -> Option<String> {
+        rewriter
+            .rewrite(line.as_bytes())
+            .map(|line| String::from_utf8(line).unwrap())
+    }
+
+    #[test]
+    fn zoned_timestamps_move_to_the_chosen_offset() {
+        let rewriter = rewriter("+02:00", TimestampFormat::Iso8601);
+        assert_eq!(
+            rewrite(
+                &rewriter,
+                "db 2024-10-18T22:30:00.153Z up, web 2024-10-18 23:30:01 +01:00 up"
+            ),
+            Some("db 2024-10-19T00:30:00.153+02:00 up, web 2024-10-19T00:30:01+02:00 up".to_string())
+        );
+    }
+
+    #[test]
+    fn formats_drop_what_they_leave_out() {
+        let line = "2024-10-18T22:30:00.153+00:00 INFO ready";
+        assert_eq!(
+            rewrite(&rewriter("UTC", TimestampFormat::DateTime), line),
+            Some("2024-10-18 22:30:00.153 INFO ready".to_string())
+        );
+        assert_eq!(
+            rewrite(&rewriter("-05:00", TimestampFormat::Time), line),
+            Some("17:30:00.153 INFO ready".to_string())
+        );
+    }
+
+    #[test]
+    fn timestamps_without_a_zone_are_left_alone() {
+        let rewriter = rewriter("UTC", TimestampFormat::Iso8601);
+        assert_eq!(rewrite(&rewriter, "2024-10-18 22:30:00 INFO ready"), None);
+        assert_eq!(rewrite(&rewriter, "plain line"), None);
+    }
+
+    #[test]
+    fn bytes_that_are_not_utf8_are_kept() {
+        let rewriter = rewriter("UTC", TimestampFormat::Iso8601);
+        assert_eq!(
+            rewriter.rewrite(b"\xff 2024-10-18T22:30:00+02:00 \xc3(ok\xe2\x82"),
+            Some(b"\xff 2024-10-18T20:30:00Z \xc3(ok\xe2\x82".to_vec())
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use nu_ansi_term::Color::{Magenta, Yellow};
+use std::fmt;
 use std::fs;
 use std::io;
 use std::path::PathBuf;
@@ -8,9 +9,57 @@ use thiserror::Error;
 pub enum Source {
     File(FileInfo),
     Command(String),
+    Listen(ListenAddr),
+    Journal(JournalSource),
     Stdin,
 }
 
+/// Journal entries read from `input`: a file or stdin when given, otherwise
+/// `journalctl` itself.
+#[derive(Debug, PartialEq, Eq)]
+pub struct JournalSource {
+    pub input: Box<Source>,
+    pub units: Vec<String>,
+}
+
+/// A `udp://` or `tcp://` address for `--listen`, with the scheme stripped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListenAddr {
+    Udp(String),
+    Tcp(String),
+}
+
+impl ListenAddr {
+    pub fn parse(s: &str) -> Result<ListenAddr, RoutingError> {
+        let (scheme, host) = s
+            .split_once("://")
+            .ok_or_else(|| RoutingError::InvalidListenAddress(s.to_owned()))?;
+
+        // Hostnames are resolved when binding; only the shape is checked here.
+        let has_port = host
+            .rsplit_once(':')
+            .is_some_and(|(name, port)| !name.is_empty() && port.parse::<u16>().is_ok());
+        if !has_port {
+            return Err(RoutingError::InvalidListenAddress(s.to_owned()));
+        }
+
+        match scheme.to_ascii_lowercase().as_str() {
+            "udp" => Ok(ListenAddr::Udp(host.to_owned())),
+            "tcp" => Ok(ListenAddr::Tcp(host.to_owned())),
+            _ => Err(RoutingError::InvalidListenAddress(s.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for ListenAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListenAddr::Udp(host) => write!(f, "udp://{host}"),
+            ListenAddr::Tcp(host) => write!(f, "tcp://{host}"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct FileInfo {
     pub path: PathBuf,
@@ -40,6 +89,15 @@ pub enum RoutingError {
     #[error("Cannot read from both file and {}", Magenta.paint("--exec").to_string())]
     CannotReadBothFileAndExec,
 
+    #[error("Cannot combine {} with a file or {}", Magenta.paint("--listen").to_string(), Magenta.paint("--exec").to_string())]
+    CannotCombineListen,
+
+    #[error("Cannot combine {} with {} or {}", Magenta.paint("--journal").to_string(), Magenta.paint("--exec").to_string(), Magenta.paint("--listen").to_string())]
+    CannotCombineJournal,
+
+    #[error("Invalid listen address {0} (expected udp://HOST:PORT or tcp://HOST:PORT)")]
+    InvalidListenAddress(String),
+
     #[error("Could not determine input type")]
     CouldNotDetermineInputType,
 
@@ -63,6 +121,9 @@ pub enum RoutingError {
 pub struct IoArgs {
     pub file_path: Option<PathBuf>,
     pub exec: Option<String>,
+    pub listen: Option<ListenAddr>,
+    pub journal: bool,
+    pub units: Vec<String>,
     pub to_stdout: bool,
     pub follow: bool,
     pub pager: Option<String>,
@@ -81,18 +142,26 @@ fn get_source(args: &IoArgs) -> Result<Source, RoutingError> {
         return Err(RoutingError::CannotReadBothFileAndExec);
     }
 
+    if args.listen.is_some() && (args.file_path.is_some() || args.exec.is_some()) {
+        return Err(RoutingError::CannotCombineListen);
+    }
+
+    if args.journal {
+        return get_journal_source(args);
+    }
+
     if let Some(path) = &args.file_path {
-        // Print mode without --follow is the only case that ends at EOF: with
-        // a pager the reader keeps following so the backing temp file picks
-        // up new lines the pager can reveal (reload or press F in less).
-        let terminate_after_first_read = args.to_stdout && !args.follow;
-        return process_path_input(path.into(), terminate_after_first_read);
+        return process_path_input(path.into(), terminate_after_first_read(args));
     }
 
     if let Some(command) = &args.exec {
         return Ok(Source::Command(command.clone()));
     }
 
+    if let Some(addr) = &args.listen {
+        return Ok(Source::Listen(addr.clone()));
+    }
+
     if args.std_in_has_data {
         return Ok(Source::Stdin);
     }
@@ -100,8 +169,56 @@ fn get_source(args: &IoArgs) -> Result<Source, RoutingError> {
     Err(RoutingError::CouldNotDetermineInputType)
 }
 
+fn get_journal_source(args: &IoArgs) -> Result<Source, RoutingError> {
+    if args.exec.is_some() || args.listen.is_some() {
+        return Err(RoutingError::CannotCombineJournal);
+    }
+
+    let input = if let Some(path) = &args.file_path {
+        process_path_input(path.into(), terminate_after_first_read(args))?
+    } else if args.std_in_has_data {
+        Source::Stdin
+    } else {
+        Source::Command(journalctl_command(&args.units, args.follow))
+    };
+
+    Ok(Source::Journal(JournalSource {
+        input: Box::new(input),
+        units: args.units.clone(),
+    }))
+}
+
+/// Mirrors `journalctl` itself: the whole journal, or its tail with `--follow`.
+fn journalctl_command(units: &[String], follow: bool) -> String {
+    let mut command = String::from("journalctl --output=json");
+    if follow {
+        command.push_str(" --follow");
+    }
+    for unit in units {
+        command.push_str(" --unit=");
+        command.push_str(&shell_words::quote(unit));
+    }
+
+    command
+}
+
+/// Print mode without --follow is the only case that ends at EOF: with a
+/// pager the reader keeps following so the backing temp file picks up new
+/// lines the pager can reveal (reload or press F in less).
+fn terminate_after_first_read(args: &IoArgs) -> bool {
+    args.to_stdout && !args.follow
+}
+
+fn reads_stdin(source: &Source) -> bool {
+    match source {
+        Source::Stdin => true,
+        Source::Journal(journal) => *journal.input == Source::Stdin,
+        _ => false,
+    }
+}
+
 fn get_target(args: &IoArgs, input: &Source) -> Result<Target, RoutingError> {
-    if *input == Source::Stdin || args.to_stdout {
+    if reads_stdin(input) || args.to_stdout {
         return Ok(Target::Stdout);
     }
 
@@ -111,7 +228,9 @@ fn get_target(args: &IoArgs, input: &Source) -> Result<Target, RoutingError> {
         return Ok(Target::CustomPager(custom_pager_options));
     }
 
-    let follow_mode = if args.exec.is_some() { true } else { args.follow };
+    // Commands and sockets only ever stream, so there is nothing to page
+    // through until new lines arrive.
+    let follow_mode = args.follow || matches!(input, Source::Command(_) | Source::Listen(_));
 
     Ok(Target::Less(LessOptions { follow: follow_mode }))
 }
@@ -158,6 +277,120 @@ fn process_path_input(path: PathBuf, terminate_after_first_read: bool) -> Result
 mod tests {
     use super::*;
 
+    fn io_args() -> IoArgs {
+        IoArgs {
+            file_path: None,
+            exec: None,
+            listen: None,
+            journal: false,
+            units: Vec::new(),
+            to_stdout: false,
+            follow: false,
+            pager: None,
+            std_in_has_data: false,
+        }
+    }
+
+    #[test]
+    fn listen_addresses_parse_by_scheme() {
+        assert_eq!(
+            ListenAddr::parse("udp://0.0.0.0:5514").unwrap(),
+            ListenAddr::Udp("0.0.0.0:5514".into())
+        );
+        assert_eq!(
+            ListenAddr::parse("TCP://localhost:601").unwrap(),
+            ListenAddr::Tcp("localhost:601".into())
+        );
+        assert_eq!(
+            ListenAddr::parse("udp://[::1]:514").unwrap(),
+            ListenAddr::Udp("[::1]:514".into())
+        );
+    }
+
+    #[test]
+    fn malformed_listen_addresses_are_rejected() {
+        for raw in [
+            "0.0.0.0:5514",
+            "http://0.0.0.0:5514",
+            "udp://0.0.0.0",
+            "udp://:5514",
+            "tcp://host:99999",
+        ] {
+            let err = ListenAddr::parse(raw).unwrap_err();
+            assert!(matches!(err, RoutingError::InvalidListenAddress(_)), "{raw}");
+        }
+    }
+
+    #[test]
+    fn listen_takes_precedence_over_stdin_and_follows_in_the_pager() {
+        let (source, target) = resolve(IoArgs {
+            listen: Some(ListenAddr::Udp("127.0.0.1:5514".into())),
+            std_in_has_data: true,
+            ..io_args()
+        })
+        .unwrap();
+
+        assert_eq!(source, Source::Listen(ListenAddr::Udp("127.0.0.1:5514".into())));
+        assert!(matches!(target, Target::Less(LessOptions { follow: true })));
+    }
+
+    #[test]
+    fn journal_without_input_runs_journalctl() {
+        let (source, _) = resolve(IoArgs {
+            journal: true,
+            units: vec!["nginx".into(), "my app.service".into()],
+            follow: true,
+            ..io_args()
+        })
+        .unwrap();
+
+        let Source::Journal(journal) = source else {
+            panic!("Expected Source::Journal, got {source:?}");
+        };
+        assert_eq!(
+            *journal.input,
+            Source::Command("journalctl --output=json --follow --unit=nginx --unit='my app.service'".into())
+        );
+        assert_eq!(journal.units, ["nginx", "my app.service"]);
+    }
+
+    #[test]
+    fn journal_from_stdin_prints_to_stdout() {
+        let (source, target) = resolve(IoArgs {
+            journal: true,
+            std_in_has_data: true,
+            ..io_args()
+        })
+        .unwrap();
+
+        assert!(matches!(source, Source::Journal(JournalSource { ref input, .. }) if **input == Source::Stdin));
+        assert!(matches!(target, Target::Stdout));
+    }
+
+    #[test]
+    fn journal_cannot_be_combined_with_exec() {
+        let err = resolve(IoArgs {
+            journal: true,
+            exec: Some("echo hi".into()),
+            ..io_args()
+        })
+        .unwrap_err();
+
+        assert!(matches!(err, RoutingError::CannotCombineJournal));
+    }
+
+    #[test]
+    fn listen_cannot_be_combined_with_exec() {
+        let err = resolve(IoArgs {
+            listen: Some(ListenAddr::Tcp("127.0.0.1:601".into())),
+            exec: Some("echo hi".into()),
+            ..io_args()
+        })
+        .unwrap_err();
+
+        assert!(matches!(err, RoutingError::CannotCombineListen));
+    }
+
     #[test]
     fn pager_command_with_placeholder_splits_into_command_and_args() {
         let options = split_custom_pager_command("ov -f [FILE]").unwrap();
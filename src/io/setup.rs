@@ -1,9 +1,14 @@
 use crate::io::presenter::Presenter;
 use crate::io::presenter::pager::{Pager, PagerOptions};
 use crate::io::reader::Reader;
+use crate::io::reader::align::AlignReader;
 use crate::io::reader::command::CommandReader;
+use crate::io::reader::delta::DeltaReader;
 use crate::io::reader::file_reader::FileReader;
+use crate::io::reader::journal::JournalReader;
+use crate::io::reader::listen::ListenReader;
 use crate::io::reader::stdin::StdinReader;
+use crate::io::reader::time_zone::TimeZoneReader;
 use crate::io::routing::{Source, Target};
 use crate::io::writer::Writer;
 use crate::io::writer::stdout::StdoutWriter;
@@ -11,6 +16,7 @@ use crate::io::writer::temp_file::TempFile;
 use anyhow::{Context, Result};
 use std::fs::File;
 use std::io::BufWriter;
+use tailspin::time::{TimestampFormat, UtcOffset};
 use tempfile::TempPath;
 
 pub struct IoSetup {
@@ -19,8 +25,23 @@ pub struct IoSetup {
     pub presenter: Presenter,
 }
 
-pub fn initialize_io(source: Source, target: Target) -> Result<IoSetup> {
-    let reader = get_reader(source)?;
+pub fn initialize_io(
+    source: Source,
+    target: Target,
+    align_prefixes: bool,
+    time_zone: Option<(UtcOffset, TimestampFormat)>,
+    deltas: bool,
+) -> Result<IoSetup> {
+    let mut reader = get_reader(source)?;
+    if let Some((offset, format)) = time_zone {
+        reader = Reader::TimeZone(TimeZoneReader::new(reader, offset, format));
+    }
+    if align_prefixes {
+        reader = Reader::Align(AlignReader::new(reader));
+    }
+    if deltas {
+        reader = Reader::Delta(DeltaReader::new(reader));
+    }
     let (writer, presenter) = get_writer_and_presenter(target)?;
 
     Ok(IoSetup {
@@ -35,6 +56,8 @@ fn get_reader(input: Source) -> Result<Reader> {
         Source::File(file) => Reader::File(FileReader::new(file.path, file.terminate_after_first_read)?),
         Source::Stdin => Reader::Stdin(StdinReader::new()),
         Source::Command(cmd) => Reader::Command(CommandReader::new(cmd)?),
+        Source::Listen(addr) => Reader::Listen(ListenReader::new(&addr)?),
+        Source::Journal(journal) => Reader::Journal(JournalReader::new(get_reader(*journal.input)?, journal.units)),
     };
 
     Ok(reader)
@@ -39,6 +39,7 @@
 //!             fg: Some(Color::Cyan),
 //!             ..Style::default()
 //!         },
+//!         ..NumberConfig::default()
 //!     })
 //!     .with_quote_highlighter(QuoteConfig {
 //!         quote_token: b'"',
@@ -65,9 +66,10 @@ pub use core::highlighter::{Error, Highlighter, HighlighterBuilder};
 /// Configuration support for custom highlighting themes and regex rules.
 pub mod config {
     pub use super::core::config::{
-        DateTimeConfig, DurationConfig, EmailConfig, IpV4Config, IpV6Config, JsonConfig, JvmStackTraceConfig,
-        KeyValueConfig, KeywordConfig, NumberConfig, PointerConfig, QuoteConfig, RegexConfig, UnixPathConfig,
-        UnixProcessConfig, UrlConfig, UuidConfig,
+        ByteSize, ContainerConfig, DateTimeConfig, DeltaConfig, DurationConfig, EmailConfig, EpochConfig, HashConfig,
+        HttpStatusConfig, IpV4Config, IpV6Config, JsonConfig, JvmStackTraceConfig, KeyValueConfig, KeywordConfig,
+        LineConfig, MacConfig, NumberConfig, PointerConfig, QuoteConfig, RegexConfig, SizeConfig, SourceLocationConfig,
+        SyslogConfig, TimeSpan, UnixPathConfig, UnixProcessConfig, UrlConfig, UuidConfig, WindowsPathConfig,
     };
 }
 
@@ -75,3 +77,8 @@ pub mod config {
 pub mod style {
     pub use super::core::style::{Color, Style};
 }
+
+/// Timestamps found in log text and their conversion between fixed UTC offsets.
+pub mod time {
+    pub use super::core::time::{Timestamp, TimestampFormat, TimestampMatch, TimestampScanner, UtcOffset};
+}
@@ -30,12 +30,15 @@ fn main() -> anyhow::Result<()> {
         source,
         target,
         highlighter,
+        align_prefixes,
+        time_zone,
+        deltas,
     } = get_config()?;
     let IoSetup {
         reader,
         writer,
         presenter,
-    } = initialize_io(source, target)?;
+    } = initialize_io(source, target, align_prefixes, time_zone, deltas)?;
 
     match presenter {
         Presenter::Stdout => run_to_stdout(reader, writer, &highlighter),
@@ -135,6 +138,11 @@ thread_local! {
 }
 
 fn write_lines(writer: &mut Writer, highlighter: &Highlighter, batch: &LineBatch) -> anyhow::Result<()> {
+    // In stream order, so per-container colors do not depend on the workers.
+    for range in &batch.lines {
+        highlighter.observe(&String::from_utf8_lossy(&batch.buf[range.clone()]));
+    }
+
     let highlighted: Vec<String> = batch
         .lines
         .par_iter()
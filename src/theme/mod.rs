@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use tailspin::config::*;
 
+pub mod preset;
 pub mod reader;
 
 /// `theme.toml` as written by the user. Every table deserializes directly
@@ -8,8 +9,15 @@ pub mod reader;
 #[derive(Deserialize, Debug, Default)]
 #[serde(default, deny_unknown_fields)]
 pub struct Theme {
+    /// A theme to merge this one over, relative to this file.
+    pub extends: Option<String>,
+    /// Themes merged over `extends` and under this file, in order.
+    pub include: Vec<String>,
+    /// Layer overlapping styles instead of letting one highlight win.
+    pub compose: bool,
     pub keywords: Vec<KeywordConfig>,
     pub regexes: Vec<RegexConfig>,
+    pub lines: Vec<LineConfig>,
     pub numbers: NumberConfig,
     pub uuids: UuidConfig,
     pub quotes: QuoteConfig,
@@ -25,6 +33,15 @@ pub struct Theme {
     pub key_value_pairs: KeyValueConfig,
     pub json: JsonConfig,
     pub jvm_stack_traces: JvmStackTraceConfig,
+    pub http_status: HttpStatusConfig,
+    pub syslog: SyslogConfig,
+    pub containers: ContainerConfig,
+    pub windows_paths: WindowsPathConfig,
+    pub source_locations: SourceLocationConfig,
+    pub mac: MacConfig,
+    pub hashes: HashConfig,
+    pub sizes: SizeConfig,
+    pub deltas: DeltaConfig,
 }
 
 #[cfg(test)]
@@ -42,6 +59,7 @@ mod tests {
 
         assert!(theme.keywords.is_empty());
         assert!(theme.regexes.is_empty());
+        assert!(!theme.compose);
         assert_eq!(theme.uuids.letter, UuidConfig::default().letter);
         assert_eq!(theme.quotes.quote_token, b'"');
     }
@@ -100,6 +118,45 @@ style = { fg = "green" }"#,
         assert_eq!(theme.numbers.style, Style::new().fg(Color::Green));
     }
 
+    #[test]
+    fn size_thresholds_parse_from_human_readable_sizes() {
+        let theme = parse(
+            r#"[sizes]
+size_warn = "1.5 GB"
+size_error = "4GiB"
+percent_error = 95"#,
+        );
+
+        assert_eq!(theme.sizes.size_warn, Some(ByteSize(1_500_000_000)));
+        assert_eq!(theme.sizes.size_error, Some(ByteSize(4 << 30)));
+        assert_eq!(theme.sizes.percent_warn, None);
+        assert_eq!(theme.sizes.percent_error, Some(95.0));
+    }
+
+    #[test]
+    fn duration_and_number_thresholds_parse() {
+        let theme = parse(
+            r#"[durations]
+warn = "500ms"
+error = "5 s"
+
+[numbers]
+error = 1000"#,
+        );
+
+        assert_eq!(theme.durations.warn, TimeSpan::from_value(0.5, "s"));
+        assert_eq!(theme.durations.error, TimeSpan::from_value(5.0, "s"));
+        assert_eq!(theme.numbers.warn, None);
+        assert_eq!(theme.numbers.error, Some(1000.0));
+    }
+
+    #[test]
+    fn invalid_size_thresholds_are_rejected() {
+        let error = toml::from_str::<Theme>("[sizes]\nsize_warn = \"12 parsecs\"").unwrap_err();
+
+        assert!(error.to_string().contains("invalid size `12 parsecs`"));
+    }
+
     #[test]
     fn keywords_and_regexes_parse_into_config_lists() {
         let theme = parse(
@@ -117,11 +174,63 @@ style = { fg = "blue" }"#,
             vec![KeywordConfig {
                 words: vec!["foo".to_string()],
                 style: Style::new().bold(),
+                ..KeywordConfig::default()
             }]
         );
         assert_eq!(theme.regexes[0].regex, "x+");
     }
 
+    #[test]
+    fn keyword_matching_options_default_to_exact_whole_words() {
+        let theme = parse(
+            r#"[[keywords]]
+words = ["a"]
+
+[[keywords]]
+words = ["b"]
+case_insensitive = true
+whole_word = false"#,
+        );
+
+        assert!(!theme.keywords[0].case_insensitive);
+        assert!(theme.keywords[0].whole_word);
+        assert!(theme.keywords[1].case_insensitive);
+        assert!(!theme.keywords[1].whole_word);
+    }
+
+    #[test]
+    fn line_rules_parse_in_order() {
+        let theme = parse(
+            r#"[[lines]]
+words = ["ERROR"]
+style = { bg = "red" }
+
+[[lines]]
+regex = "WARN|ERROR"
+invert = true
+style = { faint = true }"#,
+        );
+
+        assert_eq!(theme.lines[0].words, ["ERROR"]);
+        assert_eq!(theme.lines[0].style, Style::new().on(Color::Red));
+        assert_eq!(theme.lines[1].regex.as_deref(), Some("WARN|ERROR"));
+        assert!(theme.lines[1].invert);
+    }
+
+    #[test]
+    fn regex_groups_map_capture_names_to_styles() {
+        let theme = parse(
+            r#"[[regexes]]
+regex = '(?P<svc>\w+)/(?P<ver>v\d+)'
+groups = { svc = { fg = "cyan" }, ver = { bold = true } }"#,
+        );
+
+        let regex = &theme.regexes[0];
+        assert_eq!(regex.style, Style::default());
+        assert_eq!(regex.groups["svc"], Style::new().fg(Color::Cyan));
+        assert_eq!(regex.groups["ver"], Style::new().bold());
+    }
+
     #[test]
     fn ascii_quote_token_is_accepted() {
         let theme = parse(
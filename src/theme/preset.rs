@@ -0,0 +1,43 @@
+use clap::ValueEnum;
+
+/// Styles for light terminal backgrounds, the bottom layer under `--background
+/// light`. Not a preset: it combines with one.
+pub const LIGHT: &str = include_str!("presets/light.toml");
+
+/// Themes bundled with tspin, selected with `--theme-preset`. A preset is the
+/// bottom layer: `theme.toml`, with its `extends` and `include` files, is
+/// merged over it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Preset {
+    /// Structural highlights are faint; dates, keywords and quotes keep their colors
+    Minimal,
+    /// Error lines are bold, and lines without errors or warnings faint
+    Focus,
+}
+
+impl Preset {
+    pub const fn source(self) -> &'static str {
+        match self {
+            Preset::Minimal => include_str!("presets/minimal.toml"),
+            Preset::Focus => include_str!("presets/focus.toml"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::Theme;
+
+    #[test]
+    fn every_preset_is_a_valid_theme() {
+        for preset in Preset::value_variants() {
+            toml::from_str::<Theme>(preset.source()).unwrap_or_else(|err| panic!("{preset:?}: {err}"));
+        }
+    }
+
+    #[test]
+    fn light_layer_is_a_valid_theme() {
+        toml::from_str::<Theme>(LIGHT).unwrap();
+    }
+}
@@ -1,27 +1,57 @@
 use crate::theme::Theme;
+use crate::theme::preset::{self, Preset};
 use std::env;
 use std::env::VarError;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
+use toml::{Table, Value};
 
-pub fn parse_theme(custom_theme_path: Option<&PathBuf>) -> Result<Theme, ThemeError> {
-    if let Some(path) = custom_theme_path {
-        return read_and_parse_toml(path);
+/// Top-level lists that accumulate across layers instead of replacing.
+const APPENDED: [&str; 3] = ["keywords", "regexes", "lines"];
+
+/// Reads the theme as layers, each merged over the ones before it: the light
+/// styles if the background is light, the preset, then `theme.toml`'s
+/// `extends` file, its `include` files in order, and finally `theme.toml`
+/// itself. See [`merge`] for how layers combine.
+pub fn parse_theme(
+    custom_theme_path: Option<&PathBuf>,
+    preset: Option<Preset>,
+    light_background: bool,
+) -> Result<Theme, ThemeError> {
+    let mut table = Table::new();
+    if light_background {
+        merge(&mut table, bundled(preset::LIGHT));
+    }
+    if let Some(preset) = preset {
+        merge(&mut table, bundled(preset.source()));
     }
 
-    let default_path = get_config_dir()?.join("tailspin").join("theme.toml");
+    let user = if let Some(path) = custom_theme_path {
+        read_layers(path, &mut Vec::new())?
+    } else {
+        let default_path = get_config_dir()?.join("tailspin").join("theme.toml");
 
-    match read_and_parse_toml(&default_path) {
-        Err(ThemeError::Read(_, err)) if err.kind() == io::ErrorKind::NotFound => Ok(Theme::default()),
-        other => other,
-    }
+        match read_layers(&default_path, &mut Vec::new()) {
+            Err(ThemeError::Read(path, err))
+                if err.kind() == io::ErrorKind::NotFound && path == default_path.display().to_string() =>
+            {
+                Table::new()
+            }
+            other => other?,
+        }
+    };
+    merge(&mut table, user);
+
+    Value::Table(table)
+        .try_into::<Theme>()
+        .map_err(|err| ThemeError::Parsing("theme".to_string(), err))
 }
 
 // XDG_CONFIG_HOME and HOME cover Unix (and Git Bash on Windows, which sets
 // HOME); %APPDATA% is the Windows convention for user configuration.
-fn get_config_dir() -> Result<PathBuf, ThemeError> {
+pub fn get_config_dir() -> Result<PathBuf, ThemeError> {
     expand_var_os("XDG_CONFIG_HOME")
         .or_else(|| expand_var_os("HOME").map(|home| home.join(".config")))
         .or_else(|| expand_var_os("APPDATA"))
@@ -34,12 +64,58 @@ fn expand_var_os(key: &str) -> Option<PathBuf> {
         .map(|s| shellexpand::tilde(&s).into_owned().into())
 }
 
-fn read_and_parse_toml(path: &Path) -> Result<Theme, ThemeError> {
+fn bundled(source: &str) -> Table {
+    toml::from_str(source).expect("bundled themes are valid TOML")
+}
+
+/// Reads the theme at `path` with the files it extends and includes merged
+/// under it. `visiting` holds the files being read further up the chain, so
+/// a file that references itself is an error rather than a stack overflow.
+fn read_layers(path: &Path, visiting: &mut Vec<PathBuf>) -> Result<Table, ThemeError> {
     let display_path = || path.display().to_string();
 
     let content = fs::read_to_string(path).map_err(|err| ThemeError::Read(display_path(), err))?;
 
-    toml::from_str::<Theme>(&content).map_err(|err| ThemeError::Parsing(display_path(), err))
+    // Parsing as a `Theme` first reports unknown keys and bad values with their line.
+    let theme = toml::from_str::<Theme>(&content).map_err(|err| ThemeError::Parsing(display_path(), err))?;
+    let mut own = toml::from_str::<Table>(&content).map_err(|err| ThemeError::Parsing(display_path(), err))?;
+    own.remove("extends");
+    own.remove("include");
+
+    let canonical = fs::canonicalize(path).map_err(|err| ThemeError::Read(display_path(), err))?;
+    if visiting.contains(&canonical) {
+        return Err(ThemeError::Cycle(display_path()));
+    }
+    visiting.push(canonical);
+
+    // References are relative to the file that makes them.
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let mut table = Table::new();
+    for reference in theme.extends.iter().chain(&theme.include) {
+        let reference = PathBuf::from(shellexpand::tilde(reference).into_owned());
+        merge(&mut table, read_layers(&dir.join(reference), visiting)?);
+    }
+
+    visiting.pop();
+    merge(&mut table, own);
+
+    Ok(table)
+}
+
+/// Merges `layer` over `base`. Tables override field by field, so a layer
+/// restyling `[numbers] style` keeps the base's `warn_style`; the
+/// `[[keywords]]`, `[[regexes]]` and `[[lines]]` lists append; any other
+/// value, like `compose`, replaces.
+fn merge(base: &mut Table, layer: Table) {
+    for (key, value) in layer {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Array(items)), Value::Array(more)) if APPENDED.contains(&key.as_str()) => items.extend(more),
+            (Some(Value::Table(fields)), Value::Table(more)) => fields.extend(more),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -50,6 +126,131 @@ pub enum ThemeError {
     #[error("could not parse {0}: {1}")]
     Parsing(String, toml::de::Error),
 
+    #[error("{0} extends or includes itself")]
+    Cycle(String),
+
     #[error("could not determine the home environment: {0}")]
     HomeEnvironment(#[source] VarError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tailspin::config::NumberConfig;
+    use tailspin::style::{Color, Style};
+    use tempfile::TempDir;
+
+    fn write(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+        let path = dir.path().join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn tables_override_field_by_field_and_lists_append() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            &dir,
+            "base.toml",
+            r#"
+[numbers]
+style = { fg = "red" }
+warn_style = { fg = "blue" }
+
+[[keywords]]
+words = ["base"]
+style = { fg = "red" }
+"#,
+        );
+        let theme = write(
+            &dir,
+            "theme.toml",
+            r#"
+extends = "base.toml"
+
+[numbers]
+style = { fg = "green" }
+
+[[keywords]]
+words = ["mine"]
+style = { fg = "green" }
+"#,
+        );
+
+        let theme = parse_theme(Some(&theme), None, false).unwrap();
+
+        assert_eq!(theme.numbers.style, Style::new().fg(Color::Green));
+        assert_eq!(theme.numbers.warn_style, Style::new().fg(Color::Blue));
+        assert_eq!(theme.numbers.error_style, NumberConfig::default().error_style);
+        let words: Vec<&str> = theme.keywords.iter().map(|k| k.words[0].as_str()).collect();
+        assert_eq!(words, ["base", "mine"]);
+    }
+
+    #[test]
+    fn includes_layer_in_order_over_extends() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("shared")).unwrap();
+        write(&dir, "shared/base.toml", "[numbers]\nstyle = { fg = \"red\" }\n");
+        write(&dir, "shared/blue.toml", "[numbers]\nstyle = { fg = \"blue\" }\n");
+        // Relative to the including file, not the working directory.
+        write(
+            &dir,
+            "shared/bold.toml",
+            "include = [\"blue.toml\"]\n[uuids]\nnumber = { bold = true }\n",
+        );
+        let theme = write(
+            &dir,
+            "theme.toml",
+            "extends = \"shared/base.toml\"\ninclude = [\"shared/bold.toml\"]\n",
+        );
+
+        let theme = parse_theme(Some(&theme), None, false).unwrap();
+
+        assert_eq!(theme.numbers.style, Style::new().fg(Color::Blue));
+        assert_eq!(theme.uuids.number, Style::new().bold());
+    }
+
+    #[test]
+    fn themes_merge_over_the_preset() {
+        let dir = tempfile::tempdir().unwrap();
+        let theme = write(&dir, "theme.toml", "[numbers]\nstyle = { fg = \"green\" }\n");
+
+        let theme = parse_theme(Some(&theme), Some(Preset::Minimal), false).unwrap();
+
+        assert_eq!(theme.numbers.style, Style::new().fg(Color::Green));
+        assert_eq!(theme.uuids.number, Style::new().faint());
+    }
+
+    #[test]
+    fn light_styles_lie_under_the_theme() {
+        let dir = tempfile::tempdir().unwrap();
+        let theme = write(&dir, "theme.toml", "[quotes]\nstyle = { fg = \"green\" }\n");
+
+        let theme = parse_theme(Some(&theme), None, true).unwrap();
+
+        assert_eq!(theme.quotes.style, Style::new().fg(Color::Green));
+        assert_eq!(theme.numbers.warn_style, Style::new().fg(Color::Magenta));
+    }
+
+    #[test]
+    fn cycles_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        write(&dir, "a.toml", "extends = \"b.toml\"\n");
+        let b = write(&dir, "b.toml", "include = [\"a.toml\"]\n");
+
+        let err = parse_theme(Some(&b), None, false).unwrap_err();
+
+        assert!(matches!(err, ThemeError::Cycle(_)), "{err}");
+    }
+
+    #[test]
+    fn errors_name_the_referenced_file() {
+        let dir = tempfile::tempdir().unwrap();
+        write(&dir, "base.toml", "bogus = 1\n");
+        let theme = write(&dir, "theme.toml", "extends = \"base.toml\"\n");
+
+        let err = parse_theme(Some(&theme), None, false).unwrap_err();
+
+        assert!(err.to_string().contains("base.toml"), "{err}");
+    }
+}
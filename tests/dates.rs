@@ -0,0 +1,74 @@
+//! Property tests for the date finders against the number finder: every digit
+//! of a recognized date stays out of the number style, and number-like text
+//! that cannot be a date is highlighted exactly as without the date finders.
+
+use proptest::prelude::*;
+use std::sync::LazyLock;
+use tailspin::Highlighter;
+use tailspin::config::*;
+
+/// The default number style, cyan, which no default date style shares.
+const NUMBER: &str = "\x1b[36m";
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+static DATES_AND_NUMBERS: LazyLock<Highlighter> = LazyLock::new(|| {
+    Highlighter::builder()
+        .with_date_time_highlighter(DateTimeConfig::default())
+        .with_number_highlighter(NumberConfig::default())
+        .build()
+        .unwrap()
+});
+
+static NUMBERS: LazyLock<Highlighter> = LazyLock::new(|| {
+    Highlighter::builder()
+        .with_number_highlighter(NumberConfig::default())
+        .build()
+        .unwrap()
+});
+
+/// A date in one of the month-name, compact or dotted forms.
+fn date() -> impl Strategy<Value = String> {
+    (
+        1900..2100u32,
+        1..=12usize,
+        1..=28u32,
+        0..24u32,
+        0..60u32,
+        0..60u32,
+        0..5u8,
+    )
+        .prop_map(|(year, month, day, hour, minute, second, form)| {
+            let name = MONTHS[month - 1];
+            match form {
+                0 => format!("{name} {day:>2} {hour:02}:{minute:02}:{second:02}"),
+                1 => format!("[{day:02}/{name}/{year}:{hour:02}:{minute:02}:{second:02} +0000]"),
+                2 => format!("{year}{month:02}{day:02}T{hour:02}{minute:02}{second:02}Z"),
+                3 => format!("{year}.{month:02}.{day:02}"),
+                _ => format!("{day:02}.{month:02}.{year}"),
+            }
+        })
+}
+
+/// Integers, decimals and versions without a two-digit component, which every
+/// date form needs.
+fn number() -> impl Strategy<Value = String> {
+    "(?:[0-9]|[0-9]{3,6})(?:\\.(?:[0-9]|[0-9]{3,6})){0,3}"
+}
+
+proptest! {
+    #[test]
+    fn dates_are_not_split_into_numbers(date in date()) {
+        let input = format!("at {date} ok");
+        let output = DATES_AND_NUMBERS.apply(&input);
+        prop_assert!(!output.contains(NUMBER), "{output:?}");
+    }
+
+    #[test]
+    fn numbers_are_unaffected_by_date_finders(number in number()) {
+        let input = format!("took {number} units");
+        prop_assert_eq!(DATES_AND_NUMBERS.apply(&input), NUMBERS.apply(&input));
+    }
+}
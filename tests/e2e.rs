@@ -16,7 +16,12 @@ fn tspin() -> Command {
     cmd.env("XDG_CONFIG_HOME", EMPTY_CONFIG_DIR.path())
         .env_remove("TAILSPIN_PAGER")
         .env_remove("TAILSPIN_EXTRAS")
-        .env_remove("TAILSPIN_THEME");
+        .env_remove("TAILSPIN_THEME")
+        .env_remove("TAILSPIN_CONFIG")
+        .env_remove("COLORFGBG")
+        .env_remove("NO_COLOR")
+        // Output is captured, so styles must be forced to be asserted on.
+        .env("CLICOLOR_FORCE", "1");
     cmd
 }
 
@@ -44,7 +49,12 @@ fn file_input_highlights_with_default_theme() {
 #[test]
 fn file_input_highlights_with_all_extras() {
     let output = tspin()
-        .args(["-p", "--extras", "ipv6,jvm-stack-trace", FIXTURE])
+        .args([
+            "-p",
+            "--extras",
+            "ipv6,jvm-stack-trace,http-status,syslog,containers,windows-paths,source-locations,mac,hashes,sizes,epochs",
+            FIXTURE,
+        ])
         .output()
         .unwrap();
 
@@ -60,6 +70,95 @@ fn stdin_input_is_highlighted() {
     insta::assert_snapshot!(readable(&output));
 }
 
+#[test]
+fn decode_epochs_appends_the_utc_time() {
+    let output = tspin()
+        .arg("--decode-epochs")
+        .write_stdin("{\"ts\":1729245600.123}\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout_of(&output).contains("(2024-10-18T10:00:00.123Z)"));
+}
+
+#[test]
+fn tz_rewrites_zoned_timestamps() {
+    let output = tspin()
+        .args([
+            "--tz",
+            "+02:00",
+            "--tz-format",
+            "date-time",
+            "--disable",
+            "dates,numbers",
+        ])
+        .write_stdin("2024-10-18T22:30:00.153Z db up\n2024-10-18 22:30:00 web up\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout_of(&output),
+        "2024-10-19 00:30:00.153 db up\n2024-10-18 22:30:00 web up\n"
+    );
+}
+
+#[test]
+fn deltas_prefix_the_time_since_the_previous_line() {
+    let output = tspin()
+        .args(["--deltas", "--disable", "dates,numbers,keywords"])
+        .write_stdin("2024-10-18 22:30:00.000 start\nno timestamp\n2024-10-18 22:30:01.153 ready\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout_of(&output),
+        concat!(
+            "  \u{1b}[2m+0.000s\u{1b}[0m 2024-10-18 22:30:00.000 start\n",
+            "          no timestamp\n",
+            "  \u{1b}[1;33m+1.153s\u{1b}[0m 2024-10-18 22:30:01.153 ready\n",
+        )
+    );
+}
+
+#[test]
+fn containers_get_colors_in_the_order_they_appear() {
+    let input = "api-1  | 0\nweb-1  | 0\n".repeat(200);
+    let output = tspin()
+        .args(["--extras", "containers", "--disable", "numbers"])
+        .write_stdin(input)
+        .output()
+        .unwrap();
+
+    let stdout = stdout_of(&output);
+    assert!(stdout.starts_with("\x1b[36mapi-1\x1b[0m"), "{stdout:?}");
+    assert!(stdout.contains("\x1b[35mweb-1\x1b[0m"), "{stdout:?}");
+}
+
+#[test]
+fn deltas_keep_line_start_formats_highlighted() {
+    let input = "{\"ts\":\"2024-10-18 22:30:00.000\",\"msg\":\"ok\"}\nweb-1  | listening\n";
+    let run = |args: &[&str]| {
+        tspin()
+            .args(["--extras", "containers"])
+            .args(args)
+            .write_stdin(input)
+            .output()
+            .unwrap()
+    };
+
+    let plain = stdout_of(&run(&[]));
+    let with_deltas = stdout_of(&run(&["--deltas"]));
+
+    let (json, container) = plain.split_once('\n').unwrap();
+    let (json_with_delta, container_with_delta) = with_deltas.split_once('\n').unwrap();
+    assert!(json.starts_with("\x1b["), "{json}");
+    assert!(json_with_delta.ends_with(json), "{json_with_delta}");
+    assert_eq!(container_with_delta, format!("          {container}"));
+}
+
 #[test]
 fn stdin_edge_inputs_roundtrip() {
     let cases = [
@@ -171,6 +270,69 @@ fn file_and_exec_together_error() {
     assert!(stderr_of(&output).contains("Cannot read from both file and"));
 }
 
+#[test]
+fn listen_and_exec_together_error() {
+    let output = tspin()
+        .args(["--listen", "udp://127.0.0.1:5514", "--exec", "echo hi"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr_of(&output).contains("Cannot combine"));
+}
+
+#[test]
+fn malformed_listen_address_errors() {
+    let output = tspin().args(["--listen", "0.0.0.0:5514"]).output().unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr_of(&output).contains("expected udp://HOST:PORT or tcp://HOST:PORT"));
+}
+
+#[test]
+fn journal_json_from_stdin_is_rendered_and_filtered() {
+    let input = concat!(
+        r#"{"_SYSTEMD_UNIT":"nginx.service","_PID":"42","PRIORITY":"3","MESSAGE":"upstream timed out"}"#,
+        "\n",
+        r#"{"_SYSTEMD_UNIT":"cron.service","_PID":"7","PRIORITY":"6","MESSAGE":"tick"}"#,
+        "\n",
+    );
+    let output = tspin()
+        .args(["--journal", "--unit", "nginx"])
+        .write_stdin(input)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = stdout_of(&output);
+    assert!(stdout.contains("upstream timed out"));
+    assert!(stdout.contains("ERROR"));
+    assert!(!stdout.contains("tick"));
+}
+
+#[test]
+fn unit_requires_journal() {
+    let output = tspin().args(["--unit", "nginx", FIXTURE]).output().unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr_of(&output).contains("--journal"));
+}
+
+#[test]
+fn align_prefixes_lines_up_container_messages() {
+    let output = tspin()
+        .args(["--align-prefixes", "--enable", "keywords"])
+        .write_stdin("[pod/a/app] one\n[pod/web-0/app] two\n[pod/a/app] three\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout_of(&output),
+        "[pod/a/app] one\n[pod/web-0/app] two\n[pod/a/app]     three\n"
+    );
+}
+
 #[test]
 fn missing_file_errors() {
     let output = tspin().arg("definitely/not/a/file.log").output().unwrap();
@@ -215,6 +377,10 @@ fn spawn_tspin(args: &[&str]) -> std::process::Child {
         .env_remove("TAILSPIN_PAGER")
         .env_remove("TAILSPIN_EXTRAS")
         .env_remove("TAILSPIN_THEME")
+        .env_remove("TAILSPIN_CONFIG")
+        .env_remove("COLORFGBG")
+        .env_remove("NO_COLOR")
+        .env("CLICOLOR_FORCE", "1")
         .stdin(std::process::Stdio::null())
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
@@ -252,6 +418,35 @@ fn output_once_killed(child: std::process::Child, what: &str, marker: &str) -> O
     child.wait_with_output().unwrap()
 }
 
+#[cfg(unix)]
+#[test]
+fn listen_highlights_lines_from_a_tcp_sender() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    // Borrow a free port from the OS; tspin rebinds it right after
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let addr = format!("127.0.0.1:{port}");
+    let mut child = spawn_tspin(&["--listen", &format!("tcp://{addr}"), "--print"]);
+
+    let mut sender = None;
+    wait_until("tspin to listen", || {
+        sender = TcpStream::connect(&addr).ok();
+        sender.is_some()
+    });
+    sender.unwrap().write_all(b"ERROR disk full\n").unwrap();
+
+    let mut line = String::new();
+    BufReader::new(child.stdout.take().unwrap())
+        .read_line(&mut line)
+        .unwrap();
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert!(line.contains("\x1b["), "received lines are highlighted: {line:?}");
+    assert!(line.contains("disk full"));
+}
+
 #[cfg(unix)]
 #[test]
 fn quitting_the_pager_kills_the_exec_child() {
@@ -332,6 +527,10 @@ fn tspin_survives_sigint_while_pager_runs() {
         .env_remove("TAILSPIN_PAGER")
         .env_remove("TAILSPIN_EXTRAS")
         .env_remove("TAILSPIN_THEME")
+        .env_remove("TAILSPIN_CONFIG")
+        .env_remove("COLORFGBG")
+        .env_remove("NO_COLOR")
+        .env("CLICOLOR_FORCE", "1")
         .stdin(std::process::Stdio::null())
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
@@ -370,6 +569,10 @@ fn pager_killed_by_ctrl_c_is_a_quiet_quit() {
         .env_remove("TAILSPIN_PAGER")
         .env_remove("TAILSPIN_EXTRAS")
         .env_remove("TAILSPIN_THEME")
+        .env_remove("TAILSPIN_CONFIG")
+        .env_remove("COLORFGBG")
+        .env_remove("NO_COLOR")
+        .env("CLICOLOR_FORCE", "1")
         .stdin(std::process::Stdio::null())
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
@@ -465,6 +668,229 @@ fn custom_theme_overrides_default_style() {
     );
 }
 
+#[test]
+fn theme_extends_a_base_theme_over_the_preset() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("base.toml"), "[numbers]\nstyle = { fg = \"green\" }\n").unwrap();
+    let theme = dir.path().join("theme.toml");
+    std::fs::write(&theme, "extends = \"base.toml\"\n").unwrap();
+
+    let output = tspin()
+        .args(["--theme", theme.to_str().unwrap(), "--theme-preset", "minimal"])
+        .write_stdin("count 42 at /var/log\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = stdout_of(&output);
+    assert!(
+        stdout.contains("\x1b[32m42\x1b[0m"),
+        "the base theme styles numbers: {stdout:?}"
+    );
+    assert!(stdout.contains("\x1b[2m/var/log"), "the preset fades paths: {stdout:?}");
+}
+
+#[test]
+fn light_background_swaps_styles_and_keeps_the_theme_on_top() {
+    let dir = tempfile::tempdir().unwrap();
+    let theme = dir.path().join("theme.toml");
+    std::fs::write(&theme, "[numbers]\nstyle = { fg = \"green\" }\n").unwrap();
+
+    let output = tspin()
+        .args(["--theme", theme.to_str().unwrap(), "--background", "light"])
+        .write_stdin("INFO count 42\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = stdout_of(&output);
+    assert!(
+        stdout.contains("\x1b[30mINFO"),
+        "INFO should be black, not white: {stdout:?}"
+    );
+    assert!(stdout.contains("\x1b[32m42"), "the theme still applies: {stdout:?}");
+}
+
+#[test]
+fn colorfgbg_selects_the_light_styles_on_auto() {
+    let output = tspin().env("COLORFGBG", "0;15").write_stdin("INFO\n").output().unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout_of(&output).contains("\x1b[30mINFO"));
+}
+
+#[test]
+fn light_background_keeps_keywords_in_their_group() {
+    let output = tspin()
+        .args(["--background", "light", "--disable", "keywords"])
+        .write_stdin("INFO then WARN\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(stdout_of(&output), "INFO then WARN\n");
+}
+
+#[test]
+fn piped_output_is_plain_unless_forced() {
+    let plain = tspin()
+        .env_remove("CLICOLOR_FORCE")
+        .write_stdin("count 42\n")
+        .output()
+        .unwrap();
+    let forced = tspin().write_stdin("count 42\n").output().unwrap();
+    let no_color = tspin().env("NO_COLOR", "1").write_stdin("count 42\n").output().unwrap();
+
+    assert_eq!(stdout_of(&plain), "count 42\n");
+    assert!(stdout_of(&forced).contains("\x1b[36m42"));
+    assert_eq!(stdout_of(&no_color), "count 42\n");
+}
+
+#[test]
+fn color_flag_overrides_the_environment() {
+    let always = tspin()
+        .args(["--color", "always"])
+        .env("NO_COLOR", "1")
+        .write_stdin("count 42\n")
+        .output()
+        .unwrap();
+
+    assert!(stdout_of(&always).contains("\x1b[36m42"));
+}
+
+#[test]
+fn color_never_still_formats_lines() {
+    let output = tspin()
+        .args(["--color", "never", "--deltas"])
+        .write_stdin("2024-10-18 22:30:00.000 start\n2024-10-18 22:30:01.153 ready\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout_of(&output),
+        "  +0.000s 2024-10-18 22:30:00.000 start\n  +1.153s 2024-10-18 22:30:01.153 ready\n"
+    );
+}
+
+#[test]
+fn color_never_still_decodes_epochs() {
+    let output = tspin()
+        .args(["--extras", "epochs", "--decode-epochs", "--color=never"])
+        .write_stdin("created_at=1729245600 ok\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout_of(&output),
+        "created_at=1729245600 (2024-10-18T10:00:00.000Z) ok\n"
+    );
+}
+
+#[test]
+fn color_never_still_reports_a_broken_theme() {
+    let dir = tempfile::tempdir().unwrap();
+    let theme = dir.path().join("theme.toml");
+    std::fs::write(&theme, "[numbers]\nbogus = 1\n").unwrap();
+
+    let output = tspin()
+        .args(["--color=never", "--theme", theme.to_str().unwrap()])
+        .write_stdin("x\n")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+}
+
+#[test]
+fn config_file_sets_default_flags_that_the_command_line_overrides() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("tailspin")).unwrap();
+    std::fs::write(
+        dir.path().join("tailspin").join("config.toml"),
+        "color = \"never\"\ndeltas = true\n",
+    )
+    .unwrap();
+    let input = "2024-10-18 22:30:00.000 start\n";
+
+    let defaults = tspin()
+        .env("XDG_CONFIG_HOME", dir.path())
+        .write_stdin(input)
+        .output()
+        .unwrap();
+    let overridden = tspin()
+        .env("XDG_CONFIG_HOME", dir.path())
+        .args(["--color", "always"])
+        .write_stdin(input)
+        .output()
+        .unwrap();
+
+    assert_eq!(stdout_of(&defaults), "  +0.000s 2024-10-18 22:30:00.000 start\n");
+    assert!(stdout_of(&overridden).contains("\x1b["));
+}
+
+#[test]
+fn command_line_overrides_config_groups_and_switches() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    std::fs::write(&config, "disable = [\"numbers\"]\ndeltas = true\ncolor = \"never\"\n").unwrap();
+
+    let output = tspin()
+        .args(["--config", config.to_str().unwrap(), "--enable", "dates", "--no-deltas"])
+        .write_stdin("2024-10-18 22:30:00.000 start\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{}", stderr_of(&output));
+    assert_eq!(stdout_of(&output), "2024-10-18 22:30:00.000 start\n");
+}
+
+#[test]
+fn invalid_config_file_errors_with_the_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    std::fs::write(&config, "extras = [\"ipv7\"]\n").unwrap();
+
+    let output = tspin()
+        .args(["--config", config.to_str().unwrap()])
+        .write_stdin("x\n")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = stderr_of(&output);
+    assert!(
+        stderr.contains("invalid setting in") && stderr.contains("ipv7"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn config_profiles_apply_to_the_files_they_select() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    std::fs::write(
+        &config,
+        "[[profiles]]\npath = \"*.jsonl\"\nkeywords = [{ words = [\"OOM\"], style = { fg = \"magenta\" } }]\n",
+    )
+    .unwrap();
+    let selected = dir.path().join("app.jsonl");
+    let other = dir.path().join("app.log");
+    std::fs::write(&selected, "killed: OOM\n").unwrap();
+    std::fs::write(&other, "killed: OOM\n").unwrap();
+
+    let run = |file: &std::path::Path| {
+        tspin()
+            .args(["--config", config.to_str().unwrap(), "--print", file.to_str().unwrap()])
+            .output()
+            .unwrap()
+    };
+
+    assert!(stdout_of(&run(&selected)).contains("\x1b[35mOOM"));
+    assert!(!stdout_of(&run(&other)).contains("\x1b[35mOOM"));
+}
+
 #[test]
 fn tailspin_theme_env_var_loads_the_theme() {
     let dir = tempfile::tempdir().unwrap();
@@ -59,6 +59,7 @@ fn it_works() {
                 fg: Some(Color::Cyan),
                 ..Style::default()
             },
+            ..NumberConfig::default()
         })
         .with_quote_highlighter(QuoteConfig {
             quote_token: b'"',
@@ -100,10 +101,12 @@ fn regex_overriding_keyword_badge_suppresses_padding() {
         .with_regex_highlighter(RegexConfig {
             regex: "ERR".to_string(),
             style: Style::new().fg(Color::Green),
+            ..RegexConfig::default()
         })
         .with_keyword_highlighters(vec![KeywordConfig {
             words: vec!["ERROR".to_string()],
             style: Style::new().on(Color::Red).fg(Color::White),
+            ..KeywordConfig::default()
         }])
         .build()
         .unwrap();
@@ -128,6 +131,7 @@ fn keyword_badge_without_overlap_still_padded() {
         .with_keyword_highlighters(vec![KeywordConfig {
             words: vec!["ERROR".to_string()],
             style: Style::new().on(Color::Red).fg(Color::White),
+            ..KeywordConfig::default()
         }])
         .build()
         .unwrap();
@@ -15,15 +15,26 @@ use tailspin::style::{Color, Style};
 
 fn full_highlighter(keyword_style: Style) -> Highlighter {
     Highlighter::builder()
+        .with_delta_highlighter(DeltaConfig::default(), 10)
         .with_json_highlighter(JsonConfig::default())
         .with_regex_highlighter(RegexConfig {
             regex: r"\btrace-\d+\b".to_string(),
             style: Style::new().fg(Color::Magenta),
+            ..RegexConfig::default()
         })
         .with_date_time_highlighter(DateTimeConfig::default())
-        .with_ip_v4_highlighter(IpV4Config::default())
+        .with_epoch_highlighter(EpochConfig::default())
         .with_ip_v6_highlighter(IpV6Config::default())
+        .with_ip_v4_highlighter(IpV4Config::default())
         .with_jvm_stack_trace_highlighter(JvmStackTraceConfig::default())
+        .with_http_status_highlighter(HttpStatusConfig::default())
+        .with_syslog_highlighter(SyslogConfig::default())
+        .with_container_highlighter(ContainerConfig::default())
+        .with_windows_path_highlighter(WindowsPathConfig::default())
+        .with_source_location_highlighter(SourceLocationConfig::default())
+        .with_mac_highlighter(MacConfig::default())
+        .with_hash_highlighter(HashConfig::default())
+        .with_size_highlighter(SizeConfig::default())
         .with_url_highlighter(UrlConfig::default())
         .with_email_highlighter(EmailConfig::default())
         .with_unix_path_highlighter(UnixPathConfig::default())
@@ -36,6 +47,7 @@ fn full_highlighter(keyword_style: Style) -> Highlighter {
         .with_keyword_highlighters(vec![KeywordConfig {
             words: vec!["ERROR".to_string(), "GET".to_string(), "null".to_string()],
             style: keyword_style,
+            ..KeywordConfig::default()
         }])
         .with_quote_highlighter(QuoteConfig::default())
         .build()
@@ -69,6 +81,7 @@ const FRAGMENTS: &[&str] = &[
     "trace-42",
     "3.14",
     "150ms",
+    " +1.250s ",
     "2.5s",
     "550e8400-e29b-41d4-a716-446655440000",
     "0xdeadbeef",
@@ -81,12 +94,41 @@ const FRAGMENTS: &[&str] = &[
     "~/projects/tailspin",
     "postfix/smtp[1894]",
     "key=value",
+    "status=503",
+    "Oct 11 22:14:15 mymachine su[1234]:",
+    r#"<165>1 2003-10-11T22:14:15.003Z host app - ID47 [id@1 k="v\"]"] msg"#,
+    "HTTP/1.1\" 200",
+    "[pod/web-7d4b9c-xk2p/nginx]",
+    "api-1  |",
+    "C:\\Users\\build\\src\\main.cs",
+    "\\\\server\\share\\file.txt",
+    "src/io/routing.rs:42:7",
+    "main.go:88",
+    "3c:22:fb:12:ab:cd",
+    "3C-22-FB-12-AB-CD",
+    "10.0.4.17/24",
+    "10.0.4.1:67",
+    "[2001:db8::1]:443",
+    "fe80::1%eth0",
+    "::ffff:192.168.1.1",
+    "a1b2c3d4e5",
+    "sha256:9f86d081884c7d659a2feaa0c55ad015",
+    "512MiB",
+    "1.2 GB",
+    "  +0.153s ",
+    "87%",
+    r#""ts":1729245600.123"#,
+    "created_at=1729245600123",
     "\"quoted text\"",
     "'single'",
     r#"{"a": 1, "items": [true, null], "s": "va\"lue"}"#,
     "2024-09-14T07:57:30.659Z",
     "2022-09-09 11:48:34,534",
     "09/30/2022",
+    "Oct  8 14:02:11",
+    "[18/Oct/2026:14:02:11 +0000]",
+    "20261018T140211Z",
+    "2026.10.18",
     "java.io.IOException: pipe closed\n        at com.foo.Bar.<init>(Bar.java:42)",
     "        ... 42 more",
 ];